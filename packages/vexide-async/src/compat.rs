@@ -0,0 +1,35 @@
+//! Migration aids for code written for synchronous runtimes such as PROS.
+//!
+//! Functions in this module mirror common PROS APIs so that existing code can be ported to vexide
+//! with as few changes as possible. They are intended to ease migration, and new code should prefer
+//! the equivalent functions elsewhere in vexide.
+//!
+//! # Blocking vs. Yielding
+//!
+//! vexide's runtime is cooperative, so a task can only let other tasks run by `.await`ing. Unlike
+//! PROS, where `delay` blocks the current task and lets the scheduler run others, [`delay`] returns
+//! a future that must be awaited:
+//!
+//! ```no_run
+//! use vexide::async_runtime::compat::delay;
+//!
+//! loop {
+//!     // Previously: `delay(20);`
+//!     delay(20).await;
+//! }
+//! ```
+//!
+//! Forgetting the `.await` results in an unused future warning rather than a delay.
+
+use core::time::Duration;
+
+use crate::time::{sleep, Sleep};
+
+/// Returns a future that completes after `ms` milliseconds.
+///
+/// This is equivalent to [`sleep`] with a duration of `ms` milliseconds, and is provided to ease
+/// migration from PROS's `delay` function. See the [module-level documentation](self) for more
+/// information.
+pub fn delay(ms: u32) -> Sleep {
+    sleep(Duration::from_millis(ms as u64))
+}
@@ -0,0 +1,229 @@
+//! Filtering out brief changes in a condition.
+//!
+//! Many conditions that a program reacts to flicker for a moment before settling: a limit switch
+//! bounces when pressed, a distance reading jumps across a threshold as an object passes the edge
+//! of the sensor's view, and a competition connection can drop out for a single packet.
+//! [`Debounced`] wraps any function returning a [`bool`] and only reports a change in its value once
+//! the new value has held for a configurable amount of time. [`Debouncer`] does the same for values
+//! that are sampled by the caller.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use vexide::async_runtime::debounce::Debounced;
+//!
+//! // Only consider a ring loaded once it has been in front of the sensor for 50ms.
+//! let mut ring_loaded = Debounced::new(
+//!     || matches!(distance_sensor.object(), Ok(Some(object)) if object.distance < 40),
+//!     Duration::from_millis(50),
+//! );
+//!
+//! loop {
+//!     ring_loaded.wait_for(true).await;
+//!     intake.brake(BrakeMode::Hold)?;
+//!
+//!     ring_loaded.wait_for(false).await;
+//!     intake.set_voltage(12.0)?;
+//! }
+//! ```
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use vexide_core::time::Instant;
+
+/// Filters brief changes out of a series of boolean samples.
+///
+/// This is the state behind [`Debounced`], for values that are sampled by the caller rather than
+/// by a wrapped function. Each sample passed to [`Debouncer::update`] is compared against the last
+/// value that was held for the entire window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Debouncer {
+    window: Duration,
+    /// The last value that was held for the entire window.
+    stable: Option<bool>,
+    /// When the samples first started to differ from `stable`.
+    pending_since: Option<Instant>,
+}
+
+impl Debouncer {
+    /// Creates a new debouncer that reports a new value once it has been sampled for at least
+    /// `window`.
+    ///
+    /// The first sample is reported immediately.
+    pub const fn new(window: Duration) -> Self {
+        Self {
+            window,
+            stable: None,
+            pending_since: None,
+        }
+    }
+
+    /// Returns how long a new value must be held before it is reported.
+    pub const fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Sets how long a new value must be held before it is reported.
+    ///
+    /// A window of [`Duration::ZERO`] disables debouncing, so every change is reported immediately.
+    pub fn set_window(&mut self, window: Duration) {
+        self.window = window;
+        self.pending_since = None;
+    }
+
+    /// Records a new sample, returning the last value that was held for the entire window.
+    pub fn update(&mut self, value: bool) -> bool {
+        let stable = match self.stable {
+            Some(stable) if !self.window.is_zero() => stable,
+            _ => {
+                self.stable = Some(value);
+                self.pending_since = None;
+                return value;
+            }
+        };
+
+        if value == stable {
+            self.pending_since = None;
+            return stable;
+        }
+
+        match self.pending_since {
+            Some(since) if since.elapsed() >= self.window => {
+                self.stable = Some(value);
+                self.pending_since = None;
+                value
+            }
+            Some(_) => stable,
+            None => {
+                self.pending_since = Some(Instant::now());
+                stable
+            }
+        }
+    }
+}
+
+/// A boolean condition that only changes once its new value has held for a period of time.
+///
+/// The condition is only sampled when [`Debounced::get`] is called or one of its futures is polled,
+/// so it should be checked regularly (for example, every iteration of a control loop) for the
+/// window to be measured accurately.
+///
+/// See the [module-level documentation](self) for more information.
+#[derive(Debug)]
+pub struct Debounced<F> {
+    predicate: F,
+    debouncer: Debouncer,
+}
+
+impl<F: FnMut() -> bool> Debounced<F> {
+    /// Creates a new debounced condition that changes once `predicate` has returned a new value for
+    /// at least `window`.
+    ///
+    /// The first value returned by `predicate` is reported immediately.
+    pub const fn new(predicate: F, window: Duration) -> Self {
+        Self {
+            predicate,
+            debouncer: Debouncer::new(window),
+        }
+    }
+
+    /// Returns how long the condition must hold a new value before it is reported.
+    pub const fn window(&self) -> Duration {
+        self.debouncer.window()
+    }
+
+    /// Sets how long the condition must hold a new value before it is reported.
+    ///
+    /// A window of [`Duration::ZERO`] disables debouncing, so every change is reported immediately.
+    pub fn set_window(&mut self, window: Duration) {
+        self.debouncer.set_window(window);
+    }
+
+    /// Samples the condition, returning the last value that was held for the entire window.
+    pub fn get(&mut self) -> bool {
+        let value = (self.predicate)();
+        self.debouncer.update(value)
+    }
+
+    /// Returns a future that resolves with the new value of the condition once it changes.
+    pub fn changed(&mut self) -> DebouncedChanged<'_, F> {
+        DebouncedChanged {
+            debounced: self,
+            initial: None,
+        }
+    }
+
+    /// Returns a future that resolves once the condition is `value`.
+    ///
+    /// If the condition is already `value`, the future resolves the first time it is polled.
+    pub fn wait_for(&mut self, value: bool) -> DebouncedWaitFor<'_, F> {
+        DebouncedWaitFor {
+            debounced: self,
+            value,
+        }
+    }
+
+    /// Consumes the debounced condition, returning the wrapped function.
+    pub fn into_inner(self) -> F {
+        self.predicate
+    }
+}
+
+/// A future that resolves once a [`Debounced`] condition changes.
+///
+/// This is created by [`Debounced::changed`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct DebouncedChanged<'a, F> {
+    debounced: &'a mut Debounced<F>,
+    /// The value of the condition when this future was first polled.
+    initial: Option<bool>,
+}
+
+impl<F: FnMut() -> bool> Future for DebouncedChanged<'_, F> {
+    type Output = bool;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let value = self.debounced.get();
+
+        match self.initial {
+            Some(initial) if initial != value => return Poll::Ready(value),
+            Some(_) => {}
+            None => self.initial = Some(value),
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+/// A future that resolves once a [`Debounced`] condition has a specific value.
+///
+/// This is created by [`Debounced::wait_for`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct DebouncedWaitFor<'a, F> {
+    debounced: &'a mut Debounced<F>,
+    value: bool,
+}
+
+impl<F: FnMut() -> bool> Future for DebouncedWaitFor<'_, F> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let value = self.value;
+
+        if self.debounced.get() == value {
+            return Poll::Ready(());
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
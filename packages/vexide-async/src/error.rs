@@ -0,0 +1,17 @@
+//! Errors returned by async runtime operations.
+
+use snafu::Snafu;
+
+/// An error returned when an asynchronous operation can't produce a value.
+///
+/// Combinators and synchronization primitives in the async runtime share this error type, so that
+/// failures from several of them can be propagated from the same function with `?`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Snafu)]
+pub enum AsyncError {
+    /// The operation didn't complete before its deadline.
+    ///
+    /// This is returned by [`with_timeout`](crate::time::with_timeout) and
+    /// [`with_deadline`](crate::time::with_deadline).
+    #[snafu(display("The operation timed out."))]
+    Timeout,
+}
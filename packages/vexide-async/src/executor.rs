@@ -1,22 +1,51 @@
 use alloc::{collections::VecDeque, sync::Arc};
 use core::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
     future::Future,
+    panic::Location,
     pin::Pin,
     sync::atomic::{AtomicBool, Ordering},
     task::{Context, Poll},
+    time::Duration,
 };
 
 use async_task::{Runnable, Task};
+use vexide_core::time::Instant;
 use waker_fn::waker_fn;
 
-use super::reactor::Reactor;
+use super::{reactor::Reactor, IdlePolicy};
 
 pub(crate) static EXECUTOR: Executor = Executor::new();
 
+/// How often the executor yields to VEXos under [`IdlePolicy::Spin`].
+const SPIN_YIELD_INTERVAL: Duration = Duration::from_millis(1);
+
+/// A future that records where its task was spawned each time it is polled, so that the executor
+/// can report tasks that exceed the poll budget.
+struct Tracked<F> {
+    future: F,
+    location: &'static Location<'static>,
+}
+
+impl<F: Future> Future for Tracked<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        EXECUTOR.current_location.set(Some(self.location));
+
+        // SAFETY: `future` is structurally pinned. It is never moved out of `self` or accessed
+        // without being pinned.
+        unsafe { self.map_unchecked_mut(|this| &mut this.future) }.poll(cx)
+    }
+}
+
 pub(crate) struct Executor {
     queue: RefCell<VecDeque<Runnable>>,
     reactor: RefCell<Reactor>,
+    pub(crate) poll_budget: Cell<Option<Duration>>,
+    current_location: Cell<Option<&'static Location<'static>>>,
+    pub(crate) idle_policy: Cell<IdlePolicy>,
+    last_yield: Cell<Option<Instant>>,
 }
 //SAFETY: user programs only run on a single thread cpu core and interrupts are disabled when modifying executor state.
 unsafe impl Send for Executor {}
@@ -27,10 +56,20 @@ impl Executor {
         Self {
             queue: RefCell::new(VecDeque::new()),
             reactor: RefCell::new(Reactor::new()),
+            poll_budget: Cell::new(None),
+            current_location: Cell::new(None),
+            idle_policy: Cell::new(IdlePolicy::Yield),
+            last_yield: Cell::new(None),
         }
     }
 
+    #[track_caller]
     pub fn spawn<T>(&self, future: impl Future<Output = T> + 'static) -> Task<T> {
+        let future = Tracked {
+            future,
+            location: Location::caller(),
+        };
+
         // SAFETY: `runnable` will never be moved off this thread or shared with another thread because of the `!Send + !Sync` bounds on `Self`.
         //         Both `future` and `schedule` are `'static` so they cannot be used after being freed.
         //   TODO: Make sure that the waker can never be sent off the thread.
@@ -58,12 +97,47 @@ impl Executor {
             let mut queue = self.queue.borrow_mut();
             queue.pop_front()
         };
-        match runnable {
-            Some(runnable) => {
-                runnable.run();
-                true
+        let Some(runnable) = runnable else {
+            return false;
+        };
+
+        let Some(budget) = self.poll_budget.get() else {
+            runnable.run();
+            return true;
+        };
+
+        self.current_location.set(None);
+        let start = Instant::now();
+        runnable.run();
+        let elapsed = start.elapsed();
+
+        if elapsed > budget {
+            if let Some(location) = self.current_location.get() {
+                vexide_core::io::warn!(
+                    "Task spawned at {location} ran for {elapsed:?} without yielding (budget is {budget:?})."
+                );
+            }
+        }
+
+        true
+    }
+
+    /// Returns `true` if the executor should yield to VEXos on this iteration of its loop.
+    fn should_yield(&self) -> bool {
+        match self.idle_policy.get() {
+            IdlePolicy::Yield => true,
+            IdlePolicy::Spin => {
+                let now = Instant::now();
+                let due = self
+                    .last_yield
+                    .get()
+                    .map_or(true, |last| now.duration_since(last) >= SPIN_YIELD_INTERVAL);
+
+                if due {
+                    self.last_yield.set(Some(now));
+                }
+                due
             }
-            None => false,
         }
     }
 
@@ -83,11 +157,14 @@ impl Executor {
                 }
             }
 
-            unsafe {
-                vex_sdk::vexTasksRun();
+            if self.should_yield() {
+                unsafe {
+                    vex_sdk::vexTasksRun();
+                }
             }
 
             self.tick();
+            vexide_core::io::__heartbeat_tick();
         }
     }
 }
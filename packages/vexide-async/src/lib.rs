@@ -7,21 +7,121 @@
 
 extern crate alloc;
 
+mod error;
 mod executor;
 mod reactor;
 
+pub mod compat;
+pub mod debounce;
+pub mod stream;
 pub mod task;
 pub mod time;
 
-use core::future::Future;
+use core::{future::Future, time::Duration};
 
+pub use error::AsyncError;
 use executor::EXECUTOR;
-pub use task::spawn;
+pub use task::{spawn, TaskSet};
 
 /// Blocks the current task untill a return value can be extracted from the provided future.
 ///
 /// Does not poll all futures to completion.
+#[track_caller]
 pub fn block_on<F: Future + 'static>(future: F) -> F::Output {
     let task = spawn(future);
     EXECUTOR.block_on(task)
 }
+
+/// Sets the minimum time between reactor ticks.
+///
+/// The reactor is responsible for waking futures such as [`time::Sleep`] once they are ready to make
+/// progress. By default, the interval is [`Duration::ZERO`], meaning the reactor ticks on every
+/// iteration of the executor's loop. Since each iteration of the loop also gives VEXos a chance to
+/// run its background tasks, this is limited by how long VEXos takes to service them, which is
+/// typically well under a millisecond.
+///
+/// Raising the interval reduces the CPU time spent waking futures at the cost of timer precision,
+/// as sleeping futures may complete up to one interval late. Intervals longer than a couple of
+/// milliseconds will noticeably affect control loops that rely on [`time::sleep`] for timing.
+pub fn set_reactor_interval(interval: Duration) {
+    EXECUTOR.with_reactor(|reactor| reactor.interval = interval);
+}
+
+/// Returns the minimum time between reactor ticks.
+///
+/// See [`set_reactor_interval`] for more information.
+pub fn reactor_interval() -> Duration {
+    let mut interval = Duration::ZERO;
+    EXECUTOR.with_reactor(|reactor| interval = reactor.interval);
+    interval
+}
+
+/// Sets the maximum amount of time that a task should run for before yielding back to the executor.
+///
+/// vexide's executor is cooperative, meaning that tasks only stop running when they reach an
+/// `.await` that isn't ready. A task that runs for a long time without awaiting anything (for
+/// example, a `loop` without a [`time::sleep`]) prevents every other task from running, including
+/// control loops.
+///
+/// The executor can't interrupt a task that exceeds the budget, but it will log a warning
+/// identifying where the offending task was [spawned](spawn) once it yields. This makes it easier to
+/// track down tasks that starve the rest of the program. By default there is no budget, and no
+/// warnings are logged. Measuring each poll adds a small amount of overhead, so this is best
+/// used while debugging.
+///
+/// # Examples
+///
+/// ```no_run
+/// // Warn about any task that runs for more than 5ms without yielding.
+/// vexide::async_runtime::set_poll_budget(Some(Duration::from_millis(5)));
+/// ```
+pub fn set_poll_budget(budget: Option<Duration>) {
+    EXECUTOR.poll_budget.set(budget);
+}
+
+/// Returns the poll budget set with [`set_poll_budget`], if any.
+pub fn poll_budget() -> Option<Duration> {
+    EXECUTOR.poll_budget.get()
+}
+
+/// Determines what the executor does between polling tasks.
+///
+/// See [`set_idle_policy`] for more information.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdlePolicy {
+    /// Yield to VEXos on every iteration of the executor's loop, giving it a chance to run its
+    /// background tasks.
+    ///
+    /// This is the default.
+    #[default]
+    Yield,
+
+    /// Keep polling tasks without yielding to VEXos, only yielding once every millisecond.
+    ///
+    /// This minimizes the delay between a task being woken and it being polled again.
+    Spin,
+}
+
+/// Sets what the executor does between polling tasks.
+///
+/// Each time the executor yields to VEXos, VEXos runs its own background work, such as flushing
+/// serial output, communicating with Smart devices, and drawing to the display. This usually takes
+/// well under a millisecond, but it delays any tasks that are ready to run.
+///
+/// - [`IdlePolicy::Yield`] (the default) yields on every iteration of the executor's loop. Time
+///   that isn't needed by tasks is given to VEXos, which keeps the rest of the system responsive
+///   and avoids keeping the CPU busy for no reason, reducing power draw during long idle periods.
+/// - [`IdlePolicy::Spin`] only yields once every millisecond, and spends the rest of its time
+///   polling tasks. This gives the lowest latency for tasks that are woken frequently, such as
+///   tight control loops during a match, at the cost of keeping the CPU fully busy.
+///
+/// Device readings are only updated while VEXos runs, so spinning doesn't make sensor data arrive
+/// any faster. It only reduces how long it takes tasks to react to it.
+pub fn set_idle_policy(policy: IdlePolicy) {
+    EXECUTOR.idle_policy.set(policy);
+}
+
+/// Returns the current [`IdlePolicy`] of the executor.
+pub fn idle_policy() -> IdlePolicy {
+    EXECUTOR.idle_policy.get()
+}
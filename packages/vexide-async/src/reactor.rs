@@ -1,5 +1,5 @@
 use alloc::collections::BTreeMap;
-use core::task::Waker;
+use core::{task::Waker, time::Duration};
 
 use vexide_core::time::Instant;
 
@@ -12,13 +12,20 @@ impl Sleepers {
         self.sleepers.insert(instant, waker);
     }
 
-    pub fn pop(&mut self) -> Option<Waker> {
+    /// Removes and returns the waker of the earliest sleeper whose deadline is at or before `now`.
+    pub fn pop_due(&mut self, now: Instant) -> Option<Waker> {
+        if *self.sleepers.first_key_value()?.0 > now {
+            return None;
+        }
+
         self.sleepers.pop_first().map(|(_, waker)| waker)
     }
 }
 
 pub struct Reactor {
     pub(crate) sleepers: Sleepers,
+    pub(crate) interval: Duration,
+    last_tick: Option<Instant>,
 }
 
 impl Reactor {
@@ -27,12 +34,29 @@ impl Reactor {
             sleepers: Sleepers {
                 sleepers: BTreeMap::new(),
             },
+            interval: Duration::ZERO,
+            last_tick: None,
         }
     }
 
     pub fn tick(&mut self) {
-        if let Some(sleeper) = self.sleepers.pop() {
-            sleeper.wake()
+        let now = Instant::now();
+
+        if !self.interval.is_zero() {
+            if self
+                .last_tick
+                .is_some_and(|last_tick| now.duration_since(last_tick) < self.interval)
+            {
+                return;
+            }
+
+            self.last_tick = Some(now);
+        }
+
+        // Wake every sleeper that became due since the last tick, so that none of them end up
+        // more than one interval late.
+        while let Some(sleeper) = self.sleepers.pop_due(now) {
+            sleeper.wake();
         }
     }
 }
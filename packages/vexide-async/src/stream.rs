@@ -0,0 +1,145 @@
+//! Asynchronous streams of values.
+//!
+//! A [`Stream`] is the asynchronous equivalent of an [`Iterator`], producing a sequence of values
+//! over time rather than all at once. This is the same [`Stream`] trait from `futures-core` that
+//! the rest of vexide (such as [`competition::updates`]) uses, so the combinators here work with
+//! those streams too.
+//!
+//! * [`StreamExt`] provides combinators for working with streams, such as [`StreamExt::next`] and
+//!   [`StreamExt::map`].
+//!
+//! * [`sample_stream`] turns a function that reads a sensor into a stream that yields a new reading
+//!   at a fixed interval.
+//!
+//! [`competition::updates`]: vexide_core::competition::updates
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+pub use futures_core::Stream;
+
+use crate::time::{Loop, Sleep};
+
+/// Combinators for [`Stream`]s.
+pub trait StreamExt: Stream {
+    /// Returns a future that resolves with the next value of the stream, or `None` if the stream
+    /// has finished.
+    fn next(&mut self) -> Next<'_, Self>
+    where
+        Self: Unpin,
+    {
+        Next { stream: self }
+    }
+
+    /// Transforms each value of the stream with a function.
+    fn map<T, F>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> T,
+    {
+        Map { stream: self, f }
+    }
+}
+
+impl<S: Stream + ?Sized> StreamExt for S {}
+
+/// A future that resolves with the next value of a [`Stream`].
+///
+/// See [`StreamExt::next`] for more information.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Next<'a, S: ?Sized> {
+    stream: &'a mut S,
+}
+
+impl<S: Stream + Unpin + ?Sized> Future for Next<'_, S> {
+    type Output = Option<S::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.stream).poll_next(cx)
+    }
+}
+
+/// A stream that transforms each value of another stream with a function.
+///
+/// See [`StreamExt::map`] for more information.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Map<S, F> {
+    stream: S,
+    f: F,
+}
+
+impl<S: Stream, T, F: FnMut(S::Item) -> T> Stream for Map<S, F> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // SAFETY: `stream` is structurally pinned and is never moved out of `self`. `f` is not
+        // treated as pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+        let stream = unsafe { Pin::new_unchecked(&mut this.stream) };
+
+        stream.poll_next(cx).map(|item| item.map(&mut this.f))
+    }
+}
+
+/// A stream that calls a function once every interval, yielding its return value.
+///
+/// See [`sample_stream`] for more information.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct SampleStream<F> {
+    reader: F,
+    timer: Loop,
+    sleep: Option<Sleep>,
+}
+
+// `reader` is never pinned, so the stream can be freely moved regardless of `F`.
+impl<F> Unpin for SampleStream<F> {}
+
+impl<T, F: FnMut() -> T> Stream for SampleStream<F> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(sleep) = self.sleep.as_mut() {
+            if Pin::new(sleep).poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+        }
+
+        let reading = (self.reader)();
+        self.sleep = Some(self.timer.tick());
+
+        Poll::Ready(Some(reading))
+    }
+}
+
+/// Creates a stream that yields the return value of `reader` once every `interval`.
+///
+/// The first reading is taken immediately, and each following reading is taken one interval after
+/// the last, using the same scheduling as [`Loop`]. The stream never finishes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use core::time::Duration;
+/// use vexide::async_runtime::stream::{sample_stream, StreamExt};
+///
+/// let mut distances = sample_stream(|| distance_sensor.object(), Duration::from_millis(20))
+///     .map(|object| object.ok().flatten().map(|object| object.distance));
+///
+/// while let Some(distance) = distances.next().await {
+///     println!("{:?}", distance);
+/// }
+/// ```
+pub fn sample_stream<T, F: FnMut() -> T>(reader: F, interval: Duration) -> SampleStream<F> {
+    SampleStream {
+        reader,
+        timer: Loop::new(interval),
+        sleep: None,
+    }
+}
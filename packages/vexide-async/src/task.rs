@@ -1,12 +1,162 @@
 //! Asynchronous tasks.
+//!
+//! * [`spawn`] runs a future as a task on the executor, returning a [`Task`] handle.
+//!
+//! * [`TaskSet`] polls a dynamically sized collection of futures, yielding their results as they
+//!   complete.
 
-use core::future::Future;
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
 
 pub use async_task::{FallibleTask, Task};
 
 use crate::executor::EXECUTOR;
 
 /// Spawns a new async task that can be controlled with the returned task handle.
+///
+/// The location this function is called from is used to identify the task in
+/// [poll budget](crate::set_poll_budget) warnings.
+#[track_caller]
 pub fn spawn<T>(future: impl Future<Output = T> + 'static) -> Task<T> {
     EXECUTOR.spawn(future)
 }
+
+/// A collection of futures that yields each future's output as it completes.
+///
+/// This is useful for waiting on a set of futures whose size isn't known at compile time, such as
+/// readings from a variable number of sensors. Unlike [`spawn`], futures in a [`TaskSet`] only make
+/// progress while the set is being awaited through [`TaskSet::next`], and may borrow local data.
+///
+/// # Memory Usage
+///
+/// Each future is stored in its own heap allocation, and the set itself keeps a [`Vec`] of pointers
+/// to those allocations. If the number of futures is known ahead of time, use
+/// [`TaskSet::with_capacity`] to avoid reallocating the [`Vec`] as futures are added. The allocation
+/// for a future is freed as soon as it completes.
+///
+/// Every future in the set is polled whenever the set is polled, so this is best suited for small
+/// sets of futures.
+///
+/// # Examples
+///
+/// ```no_run
+/// let mut set = TaskSet::new();
+///
+/// for sensor in &sensors {
+///     set.push(async move {
+///         sensor.next_update().await?;
+///         sensor.heading()
+///     });
+/// }
+///
+/// while let Some(heading) = set.next().await {
+///     println!("{:?}", heading);
+/// }
+/// ```
+pub struct TaskSet<'a, T> {
+    futures: Vec<Pin<Box<dyn Future<Output = T> + 'a>>>,
+}
+
+impl<'a, T> TaskSet<'a, T> {
+    /// Creates an empty set of futures.
+    pub const fn new() -> Self {
+        Self {
+            futures: Vec::new(),
+        }
+    }
+
+    /// Creates an empty set of futures with space for at least `capacity` futures.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            futures: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Adds a future to the set.
+    pub fn push(&mut self, future: impl Future<Output = T> + 'a) {
+        self.futures.push(Box::pin(future));
+    }
+
+    /// Returns the number of futures in the set that haven't completed yet.
+    pub fn len(&self) -> usize {
+        self.futures.len()
+    }
+
+    /// Returns `true` if every future in the set has completed.
+    pub fn is_empty(&self) -> bool {
+        self.futures.is_empty()
+    }
+
+    /// Removes every future from the set without polling them to completion.
+    pub fn clear(&mut self) {
+        self.futures.clear();
+    }
+
+    /// Returns a future that resolves with the output of the next future in the set to complete,
+    /// or `None` if the set is empty.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> TaskSetNext<'_, 'a, T> {
+        TaskSetNext { set: self }
+    }
+}
+
+impl<T> Default for TaskSet<'_, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for TaskSet<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaskSet")
+            .field("len", &self.futures.len())
+            .finish()
+    }
+}
+
+impl<'a, T> Extend<Pin<Box<dyn Future<Output = T> + 'a>>> for TaskSet<'a, T> {
+    fn extend<I: IntoIterator<Item = Pin<Box<dyn Future<Output = T> + 'a>>>>(&mut self, iter: I) {
+        self.futures.extend(iter);
+    }
+}
+
+/// A future that resolves with the output of the next future in a [`TaskSet`] to complete.
+///
+/// See [`TaskSet::next`] for more information.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TaskSetNext<'s, 'a, T> {
+    set: &'s mut TaskSet<'a, T>,
+}
+
+impl<T> Future for TaskSetNext<'_, '_, T> {
+    type Output = Option<T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let futures = &mut self.set.futures;
+
+        if futures.is_empty() {
+            return Poll::Ready(None);
+        }
+
+        let completed = futures.iter_mut().enumerate().find_map(|(index, future)| {
+            match future.as_mut().poll(cx) {
+                Poll::Ready(output) => Some((index, output)),
+                Poll::Pending => None,
+            }
+        });
+
+        match completed {
+            Some((index, output)) => {
+                drop(futures.swap_remove(index));
+                Poll::Ready(Some(output))
+            }
+            None => Poll::Pending,
+        }
+    }
+}
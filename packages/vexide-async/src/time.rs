@@ -7,9 +7,14 @@
 //!
 //! * [`sleep`] and [`sleep_until`] provide ways to yield control away from a future
 //!   for or until a specific instant in time.
+//!
+//! * [`with_timeout`] and [`with_deadline`] give up on a future if it takes too long.
+//!
+//! * [`Loop`] runs a loop body at a fixed rate, sleeping for the remainder of each period.
 
 use core::{
     future::Future,
+    ops::ControlFlow,
     pin::Pin,
     task::{Context, Poll},
     time::Duration,
@@ -17,7 +22,7 @@ use core::{
 
 use vexide_core::time::Instant;
 
-use crate::executor::EXECUTOR;
+use crate::{executor::EXECUTOR, AsyncError};
 
 /// A future that will complete after a certain instant is reached in time.
 #[derive(Debug)]
@@ -47,3 +52,137 @@ pub fn sleep(duration: Duration) -> Sleep {
 pub const fn sleep_until(deadline: Instant) -> Sleep {
     Sleep(deadline)
 }
+
+/// A future that resolves with the output of another future, or an error if it doesn't complete
+/// in time.
+///
+/// This is created by [`with_timeout`] and [`with_deadline`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Timeout<F> {
+    future: F,
+    deadline: Sleep,
+}
+
+impl<F: Future> Future for Timeout<F> {
+    type Output = Result<F::Output, AsyncError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `future` is never moved out of `self`, and `deadline` is never pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+        let future = unsafe { Pin::new_unchecked(&mut this.future) };
+
+        if let Poll::Ready(output) = future.poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+
+        match Pin::new(&mut this.deadline).poll(cx) {
+            Poll::Ready(()) => Poll::Ready(Err(AsyncError::Timeout)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Runs a future, giving up if it doesn't complete within `duration`.
+///
+/// The returned future resolves to [`AsyncError::Timeout`] if `future` is still pending once
+/// `duration` has passed. `future` is dropped without being polled again when this happens.
+///
+/// # Examples
+///
+/// ```no_run
+/// use core::time::Duration;
+/// use vexide::async_runtime::time::with_timeout;
+///
+/// // Wait up to 3 seconds for the IMU to finish calibrating.
+/// match with_timeout(Duration::from_secs(3), imu.calibrate()).await {
+///     Ok(result) => result?,
+///     Err(_) => println!("IMU calibration took too long, continuing anyway."),
+/// }
+/// ```
+pub fn with_timeout<F: Future>(duration: Duration, future: F) -> Timeout<F> {
+    with_deadline(Instant::now() + duration, future)
+}
+
+/// Runs a future, giving up if it doesn't complete before `deadline`.
+///
+/// See [`with_timeout`] for more information.
+pub const fn with_deadline<F: Future>(deadline: Instant, future: F) -> Timeout<F> {
+    Timeout {
+        future,
+        deadline: sleep_until(deadline),
+    }
+}
+
+/// A helper for running code at a steady rate.
+///
+/// Control loops typically do some work, then sleep for whatever time remains in their period.
+/// [`Loop`] keeps track of when each period should end, so that time spent in the loop body doesn't
+/// cause the loop to fall behind its target rate.
+///
+/// # Examples
+///
+/// ```no_run
+/// use core::{ops::ControlFlow, time::Duration};
+/// use vexide::async_runtime::time::Loop;
+///
+/// Loop::new(Duration::from_millis(10))
+///     .run(|| async {
+///         // Do work here. This will run once every 10 milliseconds.
+///         ControlFlow::<()>::Continue(())
+///     })
+///     .await;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Loop {
+    period: Duration,
+    deadline: Instant,
+}
+
+impl Loop {
+    /// Creates a new loop that runs once every `period`, with the first period starting now.
+    pub fn new(period: Duration) -> Self {
+        Self {
+            period,
+            deadline: Instant::now() + period,
+        }
+    }
+
+    /// Returns the target time between each iteration of the loop.
+    pub const fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Returns a future that sleeps until the end of the current period.
+    ///
+    /// If the loop has fallen more than an entire period behind (for example, because the loop body took
+    /// too long), the next period starts from now rather than trying to catch up with back-to-back iterations.
+    pub fn tick(&mut self) -> Sleep {
+        let deadline = self.deadline;
+        let now = Instant::now();
+
+        self.deadline = if now > deadline + self.period {
+            now + self.period
+        } else {
+            deadline + self.period
+        };
+
+        sleep_until(deadline)
+    }
+
+    /// Runs `body` once every period until it returns [`ControlFlow::Break`], then returns the value it was
+    /// broken with.
+    pub async fn run<R, F, Fut>(mut self, mut body: F) -> R
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = ControlFlow<R>>,
+    {
+        loop {
+            if let ControlFlow::Break(value) = body().await {
+                return value;
+            }
+
+            self.tick().await;
+        }
+    }
+}
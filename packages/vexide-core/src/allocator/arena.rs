@@ -0,0 +1,148 @@
+//! Bump allocation for short-lived data.
+
+use alloc::alloc::{alloc, dealloc, handle_alloc_error};
+use core::{
+    alloc::{AllocError, Allocator, Layout},
+    cell::Cell,
+    ptr::NonNull,
+};
+
+/// The alignment of an arena's backing buffer.
+const ARENA_ALIGN: usize = 16;
+
+/// A bump allocator that hands out memory from a fixed-size buffer.
+///
+/// Allocating from an arena takes a constant amount of time, since it only needs to bump an
+/// offset into its buffer. Individual allocations are never freed. Instead, the whole arena is
+/// cleared at once with [`Arena::reset`], which also takes constant time. This makes arenas a good
+/// fit for temporary buffers that are rebuilt every iteration of a control loop, such as vision or
+/// odometry scratch space, where the general-purpose allocator's latency could cause jitter.
+///
+/// An arena is used through collections that accept a custom allocator, such as
+/// [`Vec::new_in`](alloc::vec::Vec::new_in). These collections borrow the arena, so the borrow
+/// checker ensures they have all been dropped before the arena can be reset.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(allocator_api)]
+///
+/// use vexide::core::allocator::Arena;
+///
+/// let mut arena = Arena::new(4096);
+///
+/// loop {
+///     {
+///         let mut points = Vec::new_in(&arena);
+///         points.push((1.0, 2.0));
+///         // ...
+///     }
+///
+///     // All of this iteration's allocations are freed at once.
+///     arena.reset();
+///     sleep(Duration::from_millis(10)).await;
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Arena {
+    buffer: NonNull<u8>,
+    capacity: usize,
+    offset: Cell<usize>,
+}
+
+// SAFETY: The arena owns its buffer, so moving it to another task doesn't leave any references
+// behind.
+unsafe impl Send for Arena {}
+
+impl Arena {
+    /// Creates an arena with a buffer of `capacity` bytes, allocated from the global heap.
+    ///
+    /// # Panics
+    ///
+    /// Aborts the program if the buffer can't be allocated, like other heap allocations.
+    pub fn new(capacity: usize) -> Self {
+        let layout = Self::layout(capacity);
+
+        let buffer = if layout.size() == 0 {
+            // A dangling pointer with the arena's alignment, since nothing will ever be read from it.
+            NonNull::<u128>::dangling().cast()
+        } else {
+            // SAFETY: The layout has a non-zero size.
+            match NonNull::new(unsafe { alloc(layout) }) {
+                Some(buffer) => buffer,
+                None => handle_alloc_error(layout),
+            }
+        };
+
+        Self {
+            buffer,
+            capacity,
+            offset: Cell::new(0),
+        }
+    }
+
+    fn layout(capacity: usize) -> Layout {
+        Layout::from_size_align(capacity, ARENA_ALIGN).expect("Arena capacity is too large.")
+    }
+
+    /// Returns the size of the arena's buffer in bytes.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of bytes that have been allocated since the arena was last reset,
+    /// including any padding needed for alignment.
+    pub fn used(&self) -> usize {
+        self.offset.get()
+    }
+
+    /// Returns the number of bytes that are still available in the arena.
+    pub fn remaining(&self) -> usize {
+        self.capacity - self.offset.get()
+    }
+
+    /// Frees every allocation made from the arena, making its entire buffer available again.
+    pub fn reset(&mut self) {
+        self.offset.set(0);
+    }
+}
+
+// SAFETY: Allocations are only ever freed by `Arena::reset`, which requires exclusive access to the
+// arena and therefore can't run while any collection is still borrowing it. Each allocation is
+// taken from a range of the buffer that no other allocation uses until then.
+unsafe impl Allocator for &Arena {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let base = self.buffer.as_ptr() as usize;
+
+        let start = (base + self.offset.get())
+            .checked_next_multiple_of(layout.align())
+            .ok_or(AllocError)?
+            - base;
+        let end = start.checked_add(layout.size()).ok_or(AllocError)?;
+
+        if end > self.capacity {
+            return Err(AllocError);
+        }
+
+        self.offset.set(end);
+
+        // SAFETY: `start` is within the buffer (or one past its end for zero-sized allocations).
+        let ptr = unsafe { self.buffer.add(start) };
+        Ok(NonNull::slice_from_raw_parts(ptr, layout.size()))
+    }
+
+    unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {
+        // Memory is only reclaimed when the arena is reset.
+    }
+}
+
+impl Drop for Arena {
+    fn drop(&mut self) {
+        let layout = Self::layout(self.capacity);
+
+        if layout.size() != 0 {
+            // SAFETY: The buffer was allocated in `Arena::new` with this layout.
+            unsafe { dealloc(self.buffer.as_ptr(), layout) }
+        }
+    }
+}
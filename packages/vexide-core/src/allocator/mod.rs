@@ -1,6 +1,111 @@
 //! Simple allocator using the Talc on the Brain and jemalloc in the sim.
+//!
+//! # Handling Allocation Failure
+//!
+//! The brain has a limited amount of memory, and collections such as [`Vec`] and [`Box`] abort the
+//! program if an allocation fails. Code that can do without an allocation (such as logging or
+//! telemetry) can instead use the fallible helpers in this module, which return an [`AllocError`]
+//! rather than aborting:
+//!
+//! ```no_run
+//! use vexide::core::allocator;
+//!
+//! match allocator::try_vec_with_capacity::<u8>(4096) {
+//!     Ok(mut buffer) => {
+//!         // Record telemetry into the buffer.
+//!     }
+//!     // Skip this cycle rather than crashing the control loop.
+//!     Err(_) => {}
+//! }
+//! ```
+//!
+//! Existing collections can be grown fallibly with methods such as [`Vec::try_reserve`] and
+//! [`String::try_reserve`](alloc::string::String::try_reserve).
+//!
+//! # Arena Allocation
+//!
+//! For temporary data that is rebuilt on every iteration of a loop, an [`Arena`] provides
+//! constant-time allocation that can be freed all at once, avoiding the latency of the global
+//! allocator.
 
+use alloc::{alloc::Layout, boxed::Box, collections::TryReserveError, vec::Vec};
+
+use snafu::Snafu;
+
+mod arena;
 #[cfg(all(target_arch = "arm", target_os = "none"))]
 pub mod vexos;
 #[cfg(target_arch = "wasm32")]
 mod wasm;
+
+pub use arena::Arena;
+
+/// An error returned when a fallible allocation fails.
+#[derive(Debug, Clone, PartialEq, Eq, Snafu)]
+#[snafu(display("Failed to allocate {size} bytes."))]
+pub struct AllocError {
+    /// The number of bytes that were requested, if known.
+    ///
+    /// This is zero if the requested size was too large to represent.
+    pub size: usize,
+}
+
+impl From<TryReserveError> for AllocError {
+    fn from(_: TryReserveError) -> Self {
+        Self { size: 0 }
+    }
+}
+
+/// Returns the total size of the heap in bytes.
+///
+/// On the brain, this is the size of the memory region reserved for the heap by the linker script,
+/// which is all user memory not taken up by the program itself or the stack. Some of this memory is
+/// used by the allocator's own bookkeeping, so slightly less than this is available for
+/// allocations. In the WASM simulator, where the heap grows on demand, this returns `0`.
+pub fn total_heap() -> usize {
+    #[cfg(all(target_arch = "arm", target_os = "none"))]
+    return vexos::total_heap();
+
+    #[cfg(not(all(target_arch = "arm", target_os = "none")))]
+    return 0;
+}
+
+/// Allocates memory on the heap and places `value` into it, returning an error instead of aborting
+/// if the allocation fails.
+///
+/// This is the fallible equivalent of [`Box::new`].
+pub fn try_box<T>(value: T) -> Result<Box<T>, AllocError> {
+    let layout = Layout::new::<T>();
+
+    if layout.size() == 0 {
+        return Ok(Box::new(value));
+    }
+
+    // SAFETY: The layout has a non-zero size.
+    let ptr = unsafe { alloc::alloc::alloc(layout) }.cast::<T>();
+    if ptr.is_null() {
+        return Err(AllocError {
+            size: layout.size(),
+        });
+    }
+
+    // SAFETY: `ptr` was just allocated by the global allocator with the layout of `T`, so it is
+    // valid for writes and can be owned by a `Box`.
+    unsafe {
+        ptr.write(value);
+        Ok(Box::from_raw(ptr))
+    }
+}
+
+/// Creates an empty [`Vec`] with space for at least `capacity` elements, returning an error instead
+/// of aborting if the allocation fails.
+///
+/// This is the fallible equivalent of [`Vec::with_capacity`].
+pub fn try_vec_with_capacity<T>(capacity: usize) -> Result<Vec<T>, AllocError> {
+    let mut vec = Vec::new();
+    vec.try_reserve_exact(capacity).map_err(|_| AllocError {
+        size: capacity.saturating_mul(core::mem::size_of::<T>()),
+    })?;
+
+    Ok(vec)
+}
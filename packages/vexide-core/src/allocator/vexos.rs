@@ -4,7 +4,7 @@
 //! This is done automatically in the `vex-startup` crate,
 //! so you should not need to call it yourself unless you are writing your own startup implementation.
 
-use core::ptr::addr_of_mut;
+use core::ptr::{addr_of, addr_of_mut};
 
 use talc::{ErrOnOom, Span, Talc, Talck};
 
@@ -18,6 +18,12 @@ extern "C" {
 #[global_allocator]
 static ALLOCATOR: Talck<RawMutex, ErrOnOom> = Talc::new(ErrOnOom).lock();
 
+/// Returns the size of the heap region in bytes, as defined by the linker script.
+pub fn total_heap() -> usize {
+    // SAFETY: Only the addresses of the symbols are taken, they are never read from.
+    unsafe { addr_of!(__heap_end) as usize - addr_of!(__heap_start) as usize }
+}
+
 /// Initializes the heap allocator.
 ///
 /// # Safety
@@ -4,6 +4,34 @@
 //! the [`Backtrace`] type. Backtraces are helpful to attach to errors,
 //! containing information that can be used to get a chain of where an error
 //! was created.
+//!
+//! Backtraces aren't limited to panics. [`Backtrace::capture`] can be called from anywhere in a
+//! program to find out how a certain code path was reached:
+//!
+//! ```no_run
+//! use vexide::core::backtrace::Backtrace;
+//!
+//! fn unexpected_state() {
+//!     let backtrace = Backtrace::capture();
+//!
+//!     println!("{backtrace}");
+//!     for frame in &backtrace {
+//!         // Inspect each frame individually.
+//!     }
+//! }
+//! ```
+//!
+//! # Unwinding Requirements
+//!
+//! Backtraces are captured by unwinding the stack using the ARM exception handling tables
+//! (`.ARM.exidx`) in the program binary, rather than by following frame pointers, so building with
+//! `-C force-frame-pointers` is not necessary. The `armv7a-vex-v5` target enables unwind tables by
+//! default (`"default-uwtable": true`). Custom target specifications must keep this enabled, since
+//! a function without an unwind table entry ends the backtrace early. This also applies to
+//! code written in assembly or linked in from other languages without unwind information.
+//!
+//! Backtraces are always empty when the `backtraces` feature is disabled or when running on a
+//! target other than the V5 brain.
 
 use alloc::vec::Vec;
 use core::{ffi::c_void, fmt::Display};
@@ -11,6 +39,12 @@ use core::{ffi::c_void, fmt::Display};
 #[cfg(all(target_arch = "arm", feature = "backtraces"))]
 use vex_libunwind::*;
 
+#[cfg(all(target_arch = "arm", feature = "backtraces"))]
+extern "C" {
+    /// The start of the program's code, defined by the linker script.
+    static __text_start: u8;
+}
+
 /// A captured stack backtrace.
 ///
 /// This type stores the backtrace of a captured stack at a certain point in
@@ -34,6 +68,9 @@ use vex_libunwind::*;
 ///
 /// main at /path/to/project/src/main.rs:21:9
 /// ```
+///
+/// The [`Display`] implementation also prints each frame's offset from the program's
+/// [load address](Backtrace::load_address). See its documentation for more on using `addr2line`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Backtrace {
     /// The instruction pointers of each frame in the backtrace.
@@ -80,22 +117,101 @@ impl Backtrace {
                 instruction_pointer -= 1;
             }
 
+            // Stop early rather than aborting if the heap is exhausted, since backtraces are
+            // captured by the panic handler (which may be running due to an allocation failure).
+            if frames.try_reserve(1).is_err() {
+                break;
+            }
+
             frames.push(instruction_pointer as *const c_void);
         }
 
         Ok(Self { frames })
     }
+
+    /// Returns the address that the start of the program's code was loaded at, if known.
+    ///
+    /// Subtracting this from a frame's instruction pointer gives the frame's offset into the
+    /// program binary. This is `None` when backtraces aren't supported.
+    ///
+    /// # Symbolizing Addresses
+    ///
+    /// vexide programs are linked to run at a fixed address (`0x03800000`), so the instruction
+    /// pointers in a backtrace can be passed directly to `addr2line` along with the program's ELF
+    /// file. The ELF file is found in the `target` folder next to the uploaded `.bin` file, and must
+    /// be from the exact same build as the program that captured the backtrace:
+    ///
+    /// ```terminal
+    /// $ addr2line -f -C -i -e ./target/armv7a-vex-v5/debug/program_name 0x380217b 0x380209b
+    /// my_function
+    /// /path/to/project/src/main.rs:30
+    /// main
+    /// /path/to/project/src/main.rs:21
+    /// ```
+    ///
+    /// `-f` prints function names, `-C` demangles them, and `-i` includes functions that were
+    /// inlined into the frame. If the addresses don't resolve to anything, compare the load
+    /// address printed with the backtrace against the address of the `.text` section in the ELF
+    /// file (shown by `readelf -S`), and add the frame's offset to that address instead.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn load_address() -> Option<*const c_void> {
+        #[cfg(all(target_arch = "arm", feature = "backtraces"))]
+        // SAFETY: Only the address of the symbol is taken, it is never read from.
+        return Some(unsafe { core::ptr::addr_of!(__text_start) }.cast());
+
+        #[cfg(not(all(target_arch = "arm", feature = "backtraces")))]
+        return None;
+    }
+
+    /// Returns the number of frames in the backtrace.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns `true` if the backtrace doesn't contain any frames, such as when it couldn't be
+    /// captured.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Returns an iterator over the instruction pointers of each frame, starting from the most
+    /// recent call.
+    pub fn iter(&self) -> core::slice::Iter<'_, *const c_void> {
+        self.frames.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Backtrace {
+    type Item = &'a *const c_void;
+    type IntoIter = core::slice::Iter<'a, *const c_void>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
 
 impl Display for Backtrace {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let load_address = Self::load_address();
+
         writeln!(f, "stack backtrace:")?;
         for (i, frame) in self.frames.iter().enumerate() {
-            writeln!(f, "{i:>3}: {:?}", frame)?;
+            match load_address {
+                Some(base) => writeln!(
+                    f,
+                    "{i:>3}: {:?} (+{:#x})",
+                    frame,
+                    (*frame as usize).wrapping_sub(base as usize)
+                )?,
+                None => writeln!(f, "{i:>3}: {:?}", frame)?,
+            }
+        }
+        if let Some(base) = load_address {
+            writeln!(f, "load address: {base:?}")?;
         }
         write!(
             f,
-            "note: Use a symbolizer to convert stack frames to human-readable function names."
+            "note: Use a symbolizer such as `addr2line` to convert stack frames to human-readable function names."
         )?;
         Ok(())
     }
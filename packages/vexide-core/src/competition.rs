@@ -10,12 +10,15 @@ use core::{
     ops::ControlFlow,
     pin::{pin, Pin},
     task::{self, Poll},
+    time::Duration,
 };
 
 use bitflags::bitflags;
 use futures_core::Stream;
 use pin_project::pin_project;
-use vex_sdk::vexCompetitionStatus;
+use vex_sdk::{vexCompetitionStatus, vexSystemPowerupTimeGet};
+
+use crate::{sync::Mutex, time::Instant};
 
 bitflags! {
     /// The status bits returned by [`vex_sdk::vexCompetitionStatus`].
@@ -110,6 +113,18 @@ impl CompetitionStatus {
     }
 }
 
+/// The length of the autonomous period of a match.
+pub const AUTONOMOUS_DURATION: Duration = Duration::from_secs(15);
+
+/// The length of the driver control period of a match.
+pub const DRIVER_DURATION: Duration = Duration::from_secs(105);
+
+/// The most recent competition mode that a [`CompetitionRuntime`] saw begin, along with the time at
+/// which it began.
+///
+/// This is only written when the mode changes, so it is never locked while reading the status.
+static CURRENT_PERIOD: Mutex<Option<(CompetitionMode, Instant)>> = Mutex::new(None);
+
 /// Gets the current competition status flags.
 pub fn status() -> CompetitionStatus {
     CompetitionStatus::from_bits_retain(unsafe { vexCompetitionStatus() })
@@ -131,6 +146,39 @@ pub fn mode() -> CompetitionMode {
     status().mode()
 }
 
+/// Gets the time left in the current match period when under field control.
+///
+/// Returns [`None`] if the robot is not connected to field control, is disabled, or if the start of
+/// the current period was not observed.
+///
+/// The start of each period is recorded by a running [`CompetitionRuntime`] (such as the one created by
+/// [`Compete::compete`]) when it sees the competition mode change, rather than by a free-running timer,
+/// so a late start to autonomous will not cause this to desync from the field. This means that
+/// `time_remaining` always returns [`None`] in programs that don't use a [`CompetitionRuntime`], and
+/// during a period that was already in progress when the runtime started.
+pub fn time_remaining() -> Option<Duration> {
+    let status = status();
+
+    if status.system() != Some(CompetitionSystem::FieldControl) {
+        return None;
+    }
+
+    let period_duration = match status.mode() {
+        CompetitionMode::Autonomous => AUTONOMOUS_DURATION,
+        CompetitionMode::Driver => DRIVER_DURATION,
+        CompetitionMode::Disabled => return None,
+    };
+
+    let (mode, start) = (*CURRENT_PERIOD.lock_blocking())?;
+
+    // The runtime hasn't seen the latest mode change yet.
+    if mode != status.mode() {
+        return None;
+    }
+
+    Some(period_duration.saturating_sub(start.elapsed()))
+}
+
 /// A stream of updates to the competition status.
 ///
 /// See [`updates`] for more information.
@@ -173,6 +221,162 @@ pub const fn updates() -> CompetitionUpdates {
     CompetitionUpdates { last_status: None }
 }
 
+/// A transition between two competition modes.
+///
+/// See [`mode_changes`] for more information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModeChange {
+    /// The mode the robot was in before this change, or [`None`] if this is the first mode
+    /// observed by the stream.
+    pub previous: Option<CompetitionMode>,
+
+    /// The mode the robot is now in.
+    pub current: CompetitionMode,
+}
+
+impl ModeChange {
+    /// Returns `true` if the robot moved from `previous` to `current` in this change.
+    ///
+    /// The initial change (where [`ModeChange::previous`] is [`None`]) never matches.
+    pub fn is(&self, previous: CompetitionMode, current: CompetitionMode) -> bool {
+        self.previous == Some(previous) && self.current == current
+    }
+}
+
+/// A stream of changes to the competition mode.
+///
+/// See [`mode_changes`] for more information.
+pub struct ModeChanges {
+    last_mode: Option<CompetitionMode>,
+}
+
+impl Stream for ModeChanges {
+    type Item = ModeChange;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Option<Self::Item>> {
+        let current = mode();
+
+        // TODO: This should probably be done on a timer in the reactor.
+        cx.waker().wake_by_ref();
+
+        if self.last_mode != Some(current) {
+            let previous = self.get_mut().last_mode.replace(current);
+            Poll::Ready(Some(ModeChange { previous, current }))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Gets a stream of changes to the competition mode.
+///
+/// Each item reports both the previous and current mode, so a specific transition (such as from
+/// disabled to autonomous) can be told apart from others that end in the same mode. The first item
+/// is yielded when the stream is first polled and has a [`previous`](ModeChange::previous) mode of
+/// [`None`], since no earlier mode was observed.
+///
+/// Unlike [`updates`], changes to the connection state or controlling system that don't affect the
+/// mode are not reported.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide::{
+///     async_runtime::stream::StreamExt,
+///     core::competition::{self, CompetitionMode},
+/// };
+///
+/// let mut changes = competition::mode_changes();
+///
+/// while let Some(change) = changes.next().await {
+///     if change.is(CompetitionMode::Disabled, CompetitionMode::Autonomous) {
+///         println!("Starting autonomous!");
+///     }
+/// }
+/// ```
+pub const fn mode_changes() -> ModeChanges {
+    ModeChanges { last_mode: None }
+}
+
+/// The file on the SD card that records when autonomous was last completed.
+const AUTONOMOUS_COMPLETED_PATH: &str = "vexide_auton.txt";
+
+/// How long a record of autonomous being completed remains valid for.
+///
+/// This is slightly longer than a full match, so a record is always forgotten before the next one.
+const AUTONOMOUS_COMPLETED_LIFETIME: Duration =
+    Duration::from_secs(AUTONOMOUS_DURATION.as_secs() + DRIVER_DURATION.as_secs() + 60);
+
+/// Records that the autonomous routine has finished for the current match.
+///
+/// If the program crashes and is restarted during a match, it would normally run its autonomous
+/// routine again as soon as field control enters the autonomous period (or, worse, be started
+/// fresh during the driver period with no memory of the match). Calling this at the end of
+/// autonomous lets a restarted program check [`autonomous_completed`] and skip it.
+///
+/// # Storage
+///
+/// The record is stored in a small file named `vexide_auton.txt` at the root of the SD card,
+/// containing the brain's uptime when autonomous completed. The brain doesn't provide any other
+/// storage that survives a program restart. A record is only considered valid while the brain
+/// stays powered on, and for a few minutes afterward (slightly longer than a full match), so it
+/// can't affect the next match.
+///
+/// # Errors
+///
+/// Returns an error if no SD card is inserted or the file couldn't be written. Writes to the SD
+/// card are not guaranteed to be durable if the brain loses power or the card is removed while
+/// writing, so this should be treated as a best-effort safeguard.
+///
+/// # Examples
+///
+/// ```no_run
+/// async fn autonomous(&mut self) {
+///     if competition::autonomous_completed() {
+///         // The program was restarted after autonomous already ran.
+///         return;
+///     }
+///
+///     // ...
+///
+///     competition::mark_autonomous_completed().ok();
+/// }
+/// ```
+pub fn mark_autonomous_completed() -> no_std_io::io::Result<()> {
+    let uptime = unsafe { vexSystemPowerupTimeGet() };
+    crate::fs::write(AUTONOMOUS_COMPLETED_PATH, alloc::format!("{uptime}"))
+}
+
+/// Returns `true` if [`mark_autonomous_completed`] was called during the current match, including
+/// by an earlier run of the program.
+///
+/// Returns `false` if no SD card is inserted or the record can't be read. See
+/// [`mark_autonomous_completed`] for details on how the record is stored.
+pub fn autonomous_completed() -> bool {
+    let Ok(contents) = crate::fs::read_to_string(AUTONOMOUS_COMPLETED_PATH) else {
+        return false;
+    };
+    let Ok(completed_at) = contents.trim().parse::<u64>() else {
+        return false;
+    };
+
+    let uptime = unsafe { vexSystemPowerupTimeGet() };
+
+    // If the brain was power cycled, its uptime will be earlier than the recorded time.
+    completed_at <= uptime
+        && uptime - completed_at < AUTONOMOUS_COMPLETED_LIFETIME.as_micros() as u64
+}
+
+/// Forgets that autonomous has been completed, so that [`autonomous_completed`] returns `false`
+/// until [`mark_autonomous_completed`] is called again.
+///
+/// # Errors
+///
+/// Returns an error if no SD card is inserted or the file couldn't be written.
+pub fn clear_autonomous_completed() -> no_std_io::io::Result<()> {
+    crate::fs::write(AUTONOMOUS_COMPLETED_PATH, "")
+}
+
 /// A future which delegates to different futures depending on the current competition mode.
 /// I.e., a tiny async runtime specifically for writing competition programs.
 #[pin_project]
@@ -279,6 +483,10 @@ where
             Poll::Ready(Some(new_status)) => {
                 let old_status = *this.status;
 
+                if new_status.mode() != old_status.mode() {
+                    *CURRENT_PERIOD.lock_blocking() = Some((new_status.mode(), Instant::now()));
+                }
+
                 // Connected and Disconnected should not be interrupted by other changes.
                 if *this.phase != CompetitionRuntimePhase::Connected
                     && *this.phase != CompetitionRuntimePhase::Disconnected
@@ -0,0 +1,202 @@
+//! Filesystem access for the brain's SD card.
+//!
+//! This module aims to provide a similar API to the Rust standard library's `std::fs` module, backed
+//! by the SD card slot on the V5 brain.
+//!
+//! # Paths
+//!
+//! Paths are relative to the root of the SD card, so `"config/auton.txt"` refers to
+//! `auton.txt` inside of the `config` folder on the card. VEXos doesn't support creating folders,
+//! so any folders in a path must already exist on the card.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use vexide::core::fs;
+//!
+//! fs::write("hello.txt", b"Hello, world!")?;
+//! let contents = fs::read_to_string("hello.txt")?;
+//! ```
+
+use alloc::{ffi::CString, string::String, vec::Vec};
+use core::{ffi::c_char, ptr::NonNull};
+
+use no_std_io::io::{self, Read, Write};
+use vex_sdk::{
+    vexFileClose, vexFileDriveStatus, vexFileOpen, vexFileOpenCreate, vexFileOpenWrite,
+    vexFileRead, vexFileSize, vexFileSync, vexFileWrite, FIL,
+};
+
+/// Returns `true` if an SD card is inserted into the brain.
+///
+/// `vexide_devices::usd::usd_installed` is an alias for this function.
+pub fn card_inserted() -> bool {
+    unsafe { vexFileDriveStatus(0) }
+}
+
+/// An open file on the SD card.
+///
+/// Files are opened with either read or write access, and are closed when dropped.
+#[derive(Debug)]
+pub struct File {
+    fd: NonNull<FIL>,
+    writable: bool,
+}
+
+// SAFETY: The file handle is only ever accessed through `&mut File`, and user programs run on a
+// single thread.
+unsafe impl Send for File {}
+unsafe impl Sync for File {}
+
+impl File {
+    fn open_with(
+        path: &str,
+        writable: bool,
+        open: impl FnOnce(*const c_char) -> *mut FIL,
+    ) -> io::Result<Self> {
+        if !card_inserted() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "No SD card is inserted.",
+            ));
+        }
+
+        let path = CString::new(path).map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidInput, "Path contains a null byte.")
+        })?;
+
+        match NonNull::new(open(path.as_ptr())) {
+            Some(fd) => Ok(Self { fd, writable }),
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "The file could not be opened.",
+            )),
+        }
+    }
+
+    /// Opens an existing file in read-only mode.
+    ///
+    /// Returns an error of kind [`io::ErrorKind::NotFound`] if no SD card is inserted or the file
+    /// doesn't exist.
+    pub fn open(path: &str) -> io::Result<Self> {
+        Self::open_with(path, false, |path| unsafe {
+            vexFileOpen(path, c"".as_ptr())
+        })
+    }
+
+    /// Opens a file in write-only mode, creating it if it doesn't exist and truncating it if it does.
+    pub fn create(path: &str) -> io::Result<Self> {
+        Self::open_with(path, true, |path| unsafe { vexFileOpenCreate(path) })
+    }
+
+    /// Opens a file in write-only mode, creating it if it doesn't exist. Data written to the file is
+    /// appended to its existing contents.
+    pub fn append(path: &str) -> io::Result<Self> {
+        Self::open_with(path, true, |path| unsafe { vexFileOpenWrite(path) })
+    }
+
+    /// Returns the size of the file in bytes.
+    pub fn len(&self) -> usize {
+        unsafe { vexFileSize(self.fd.as_ptr()) }.max(0) as usize
+    }
+
+    /// Returns `true` if the file is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Read for File {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.writable {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "File was not opened for reading.",
+            ));
+        }
+
+        match unsafe {
+            vexFileRead(
+                buf.as_mut_ptr().cast(),
+                1,
+                buf.len() as u32,
+                self.fd.as_ptr(),
+            )
+        } {
+            read if read < 0 => Err(io::Error::new(
+                io::ErrorKind::Other,
+                "Internal read error occurred.",
+            )),
+            read => Ok(read as usize),
+        }
+    }
+}
+
+impl Write for File {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if !self.writable {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "File was not opened for writing.",
+            ));
+        }
+
+        match unsafe {
+            vexFileWrite(
+                buf.as_ptr().cast_mut().cast(),
+                1,
+                buf.len() as u32,
+                self.fd.as_ptr(),
+            )
+        } {
+            written if written < 0 => Err(io::Error::new(
+                io::ErrorKind::Other,
+                "Internal write error occurred.",
+            )),
+            written => Ok(written as usize),
+        }
+    }
+
+    /// Writes any buffered data to the SD card.
+    fn flush(&mut self) -> io::Result<()> {
+        if self.writable {
+            unsafe { vexFileSync(self.fd.as_ptr()) }
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for File {
+    fn drop(&mut self) {
+        unsafe { vexFileClose(self.fd.as_ptr()) }
+    }
+}
+
+/// Reads the entire contents of a file.
+pub fn read(path: &str) -> io::Result<Vec<u8>> {
+    let mut file = File::open(path)?;
+    let mut contents = Vec::with_capacity(file.len());
+    file.read_to_end(&mut contents)?;
+
+    Ok(contents)
+}
+
+/// Reads the entire contents of a file into a string.
+///
+/// Returns an error of kind [`io::ErrorKind::InvalidData`] if the file isn't valid UTF-8.
+pub fn read_to_string(path: &str) -> io::Result<String> {
+    String::from_utf8(read(path)?).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "File did not contain valid UTF-8.",
+        )
+    })
+}
+
+/// Writes `contents` to a file, replacing its contents if it already exists.
+pub fn write(path: &str, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    file.write_all(contents.as_ref())?;
+    file.flush()
+}
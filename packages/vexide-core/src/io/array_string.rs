@@ -0,0 +1,147 @@
+//! Fixed-capacity strings.
+
+use core::{fmt, ops::Deref, str};
+
+/// A string stored inline with a fixed capacity of `N` bytes.
+///
+/// Unlike [`String`](alloc::string::String), an `ArrayString` never allocates, which makes it useful
+/// for formatting short messages (such as log lines) at a high rate without churning the heap. Text
+/// that doesn't fit is truncated at the nearest character boundary.
+///
+/// # Examples
+///
+/// ```
+/// use core::fmt::Write;
+/// use vexide::core::io::ArrayString;
+///
+/// let mut message = ArrayString::<32>::new();
+/// write!(message, "Battery: {}%", 87).unwrap();
+///
+/// assert_eq!(message.as_str(), "Battery: 87%");
+/// ```
+#[derive(Clone, Copy)]
+pub struct ArrayString<const N: usize> {
+    buffer: [u8; N],
+    len: usize,
+    truncated: bool,
+}
+
+impl<const N: usize> ArrayString<N> {
+    /// Creates a new empty string.
+    pub const fn new() -> Self {
+        Self {
+            buffer: [0; N],
+            len: 0,
+            truncated: false,
+        }
+    }
+
+    /// Returns the contents of the string.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: `buffer[..len]` is only ever written with whole UTF-8 characters.
+        unsafe { str::from_utf8_unchecked(&self.buffer[..self.len]) }
+    }
+
+    /// Returns the length of the string in bytes.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the string is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum length of the string in bytes.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if any text was dropped because it didn't fit in the string.
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Appends as much of `s` as will fit to the end of the string.
+    ///
+    /// Returns `true` if all of `s` was appended. Otherwise, `s` is truncated at the last character
+    /// that fits, and [`ArrayString::is_truncated`] will return `true`.
+    pub fn push_str(&mut self, s: &str) -> bool {
+        let remaining = N - self.len;
+        let mut end = s.len().min(remaining);
+
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        self.buffer[self.len..self.len + end].copy_from_slice(&s.as_bytes()[..end]);
+        self.len += end;
+
+        if end < s.len() {
+            self.truncated = true;
+            return false;
+        }
+
+        true
+    }
+
+    /// Empties the string, keeping its capacity.
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.truncated = false;
+    }
+}
+
+impl<const N: usize> Default for ArrayString<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Deref for ArrayString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for ArrayString<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq for ArrayString<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for ArrayString<N> {}
+
+impl<const N: usize> fmt::Write for ArrayString<N> {
+    /// Appends `s` to the end of the string.
+    ///
+    /// If `s` doesn't fit, as much of it as possible is appended and an error is returned, which
+    /// stops any further formatting.
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.push_str(s) {
+            Ok(())
+        } else {
+            Err(fmt::Error)
+        }
+    }
+}
+
+impl<const N: usize> fmt::Display for ArrayString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Debug for ArrayString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
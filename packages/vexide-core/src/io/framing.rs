@@ -0,0 +1,304 @@
+//! Message framing for byte streams.
+//!
+//! Serial links such as [generic serial] and VEXLink only transmit a stream of bytes, so there's no
+//! way to tell where one message ends and the next begins. [`Framed`] splits a stream into
+//! discrete frames using [Consistent Overhead Byte Stuffing (COBS)][cobs], which encodes each
+//! frame without any zero bytes and then uses a single zero byte to mark the end of the frame.
+//!
+//! COBS adds at most one byte of overhead for every 254 bytes of data, plus the delimiter. If a
+//! corrupted or partially received frame is encountered, the receiver can recover by skipping to
+//! the next zero byte.
+//!
+//! Frames can optionally carry a [CRC-16](crc16) checksum using [`Framed::write_frame_checked`] and
+//! [`Framed::read_frame_checked`], allowing data corrupted in transit (such as over a noisy VEXLink
+//! radio) to be detected and rejected.
+//!
+//! [generic serial]: https://docs.rs/vexide-devices/latest/vexide_devices/smart/serial/index.html
+//! [cobs]: https://en.wikipedia.org/wiki/Consistent_Overhead_Byte_Stuffing
+
+use alloc::vec::Vec;
+
+use no_std_io::io::{self, Read, Write};
+
+/// The byte that marks the end of a COBS-encoded frame.
+const DELIMITER: u8 = 0;
+
+/// Encodes `data` using COBS and appends it to `out`.
+///
+/// The encoded data will not contain any zero bytes. The frame delimiter is not appended.
+pub fn cobs_encode(data: &[u8], out: &mut Vec<u8>) {
+    let mut code_index = out.len();
+    let mut code = 1u8;
+    out.push(0);
+
+    for &byte in data {
+        if byte == 0 {
+            out[code_index] = code;
+            code_index = out.len();
+            code = 1;
+            out.push(0);
+        } else {
+            out.push(byte);
+            code += 1;
+
+            if code == 0xFF {
+                out[code_index] = code;
+                code_index = out.len();
+                code = 1;
+                out.push(0);
+            }
+        }
+    }
+
+    out[code_index] = code;
+}
+
+/// Computes the CRC-16/CCITT-FALSE checksum of `data`.
+///
+/// This uses the polynomial `0x1021` with an initial value of `0xFFFF`, and is the checksum used by
+/// [`Framed::write_frame_checked`] and [`Framed::read_frame_checked`].
+pub const fn crc16(data: &[u8]) -> u16 {
+    let mut crc = 0xFFFFu16;
+    let mut index = 0;
+
+    while index < data.len() {
+        crc ^= (data[index] as u16) << 8;
+
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+
+        index += 1;
+    }
+
+    crc
+}
+
+/// Decodes COBS-encoded `data` (without its delimiter) and appends the result to `out`.
+///
+/// Returns an error of kind [`io::ErrorKind::InvalidData`] if `data` is not validly encoded. In
+/// that case, `out` may contain part of the decoded data.
+pub fn cobs_decode(data: &[u8], out: &mut Vec<u8>) -> io::Result<()> {
+    let mut index = 0;
+
+    while index < data.len() {
+        let code = data[index] as usize;
+        let end = index + code;
+
+        if code == 0 || end > data.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Malformed COBS frame.",
+            ));
+        }
+
+        let block = &data[index + 1..end];
+        if block.contains(&DELIMITER) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Malformed COBS frame.",
+            ));
+        }
+
+        out.extend_from_slice(block);
+        index = end;
+
+        if code != 0xFF && index < data.len() {
+            out.push(0);
+        }
+    }
+
+    Ok(())
+}
+
+/// Sends and receives COBS-encoded frames over a byte stream.
+///
+/// Each frame is encoded using [Consistent Overhead Byte Stuffing (COBS)][cobs] and followed by a
+/// zero byte delimiter. Incoming bytes are buffered internally, so frames can be split across any
+/// number of reads.
+///
+/// [cobs]: https://en.wikipedia.org/wiki/Consistent_Overhead_Byte_Stuffing
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide::core::io::Framed;
+///
+/// let serial = SerialPort::open(peripherals.port_1, 115200);
+/// let mut framed = Framed::new(serial);
+///
+/// framed.write_frame(b"hello")?;
+///
+/// loop {
+///     while let Some(frame) = framed.read_frame()? {
+///         println!("Received frame: {:?}", frame);
+///     }
+///
+///     sleep(Duration::from_millis(10)).await;
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Framed<T> {
+    inner: T,
+    buffer: Vec<u8>,
+    max_frame_length: usize,
+    discarding: bool,
+}
+
+impl<T> Framed<T> {
+    /// The default maximum length of an encoded frame.
+    pub const DEFAULT_MAX_FRAME_LENGTH: usize = 1024;
+
+    /// Creates a new framed stream with a maximum encoded frame length of
+    /// [`Framed::DEFAULT_MAX_FRAME_LENGTH`].
+    pub const fn new(inner: T) -> Self {
+        Self::with_max_frame_length(inner, Self::DEFAULT_MAX_FRAME_LENGTH)
+    }
+
+    /// Creates a new framed stream that rejects incoming frames longer than `max_frame_length` bytes
+    /// once encoded.
+    ///
+    /// This limits how much memory a single frame can occupy, which is important since stray data
+    /// without any delimiters would otherwise be buffered forever.
+    pub const fn with_max_frame_length(inner: T, max_frame_length: usize) -> Self {
+        Self {
+            inner,
+            buffer: Vec::new(),
+            max_frame_length,
+            discarding: false,
+        }
+    }
+
+    /// Returns a reference to the underlying stream.
+    pub const fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying stream.
+    ///
+    /// Reading directly from the underlying stream may cause frames to be lost or corrupted.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Consumes the framed stream, returning the underlying stream.
+    ///
+    /// Any partially received frame is discarded.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: Write> Framed<T> {
+    /// Encodes `frame` and writes it to the underlying stream, followed by a delimiter.
+    pub fn write_frame(&mut self, frame: &[u8]) -> io::Result<()> {
+        let mut encoded = Vec::with_capacity(frame.len() + frame.len() / 254 + 2);
+        cobs_encode(frame, &mut encoded);
+        encoded.push(DELIMITER);
+
+        self.inner.write_all(&encoded)
+    }
+
+    /// Writes `frame` to the underlying stream with a trailing [`crc16`] checksum.
+    ///
+    /// The receiver should read the frame with [`Framed::read_frame_checked`].
+    pub fn write_frame_checked(&mut self, frame: &[u8]) -> io::Result<()> {
+        let mut checked = Vec::with_capacity(frame.len() + 2);
+        checked.extend_from_slice(frame);
+        checked.extend_from_slice(&crc16(frame).to_be_bytes());
+
+        self.write_frame(&checked)
+    }
+}
+
+impl<T: Read> Framed<T> {
+    /// Reads any available data from the underlying stream, returning the next complete frame if
+    /// one has been received.
+    ///
+    /// Returns `Ok(None)` if a complete frame isn't available yet, in which case any partially
+    /// received data is kept for the next call. This function does not wait for data to arrive,
+    /// provided the underlying stream doesn't.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] if the received frame is malformed or
+    /// longer than the maximum frame length. The invalid frame is discarded, so the next call will
+    /// continue with the following frame.
+    pub fn read_frame(&mut self) -> io::Result<Option<Vec<u8>>> {
+        loop {
+            if let Some(end) = self.buffer.iter().position(|&byte| byte == DELIMITER) {
+                let discarded = core::mem::replace(&mut self.discarding, false);
+                let encoded: Vec<u8> = self.buffer.drain(..=end).take(end).collect();
+
+                if discarded {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "Frame exceeded the maximum frame length.",
+                    ));
+                }
+
+                // Skip empty frames, such as those caused by consecutive delimiters.
+                if encoded.is_empty() {
+                    continue;
+                }
+
+                let mut frame = Vec::with_capacity(encoded.len());
+                cobs_decode(&encoded, &mut frame)?;
+
+                return Ok(Some(frame));
+            }
+
+            if self.buffer.len() > self.max_frame_length {
+                // Drop the oversized frame's data and ignore everything up to the next delimiter.
+                self.buffer.clear();
+                self.discarding = true;
+            }
+
+            let mut chunk = [0; 64];
+            let read = self.inner.read(&mut chunk)?;
+
+            if read == 0 {
+                return Ok(None);
+            }
+
+            self.buffer.extend_from_slice(&chunk[..read]);
+        }
+    }
+
+    /// Reads the next complete frame sent with [`Framed::write_frame_checked`], verifying and
+    /// removing its checksum.
+    ///
+    /// This behaves like [`Framed::read_frame`], but additionally returns an error of kind
+    /// [`io::ErrorKind::InvalidData`] if the frame's checksum doesn't match its contents.
+    pub fn read_frame_checked(&mut self) -> io::Result<Option<Vec<u8>>> {
+        let Some(mut frame) = self.read_frame()? else {
+            return Ok(None);
+        };
+
+        if frame.len() < 2 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Frame is too short to contain a checksum.",
+            ));
+        }
+
+        let checksum_start = frame.len() - 2;
+        let checksum = u16::from_be_bytes([frame[checksum_start], frame[checksum_start + 1]]);
+        frame.truncate(checksum_start);
+
+        if crc16(&frame) != checksum {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Frame checksum mismatch.",
+            ));
+        }
+
+        Ok(Some(frame))
+    }
+}
@@ -0,0 +1,133 @@
+//! Periodic keepalive messages over serial.
+//!
+//! Tools on a connected computer (such as dashboards) can't easily tell whether a quiet program is
+//! still running or has hung. When enabled with [`enable_heartbeat`], the async executor prints a
+//! short status line at a fixed interval, so a host tool can treat a missing heartbeat as a sign that
+//! the program has stalled or crashed.
+//!
+//! # Format
+//!
+//! Each heartbeat is a single line in the following form:
+//!
+//! ```text
+//! [heartbeat] uptime_ms=15230 loops=18211 avg_loop_us=83 max_loop_us=1204
+//! ```
+//!
+//! - `uptime_ms` is the time since the program started in milliseconds.
+//! - `loops` is the number of executor loop iterations since the last heartbeat.
+//! - `avg_loop_us` and `max_loop_us` are the average and longest time between executor loop
+//!   iterations since the last heartbeat, in microseconds. A large `max_loop_us` means that some
+//!   task ran for a long time without yielding.
+//!
+//! Heartbeats are printed alongside any other serial output, so host tools should look for lines
+//! starting with `[heartbeat]` and pass everything else through.
+
+use core::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use no_std_io::io::Write;
+
+use super::stdout;
+use crate::time;
+
+/// The interval between heartbeats in microseconds, or zero if heartbeats are disabled.
+static INTERVAL_MICROS: AtomicU64 = AtomicU64::new(0);
+
+/// The time of the last heartbeat in microseconds.
+static LAST_HEARTBEAT: AtomicU64 = AtomicU64::new(0);
+
+/// The time of the last executor loop iteration in microseconds, or [`u64::MAX`] if there hasn't
+/// been one since heartbeats were enabled.
+static LAST_LOOP: AtomicU64 = AtomicU64::new(u64::MAX);
+
+/// The number of loop iterations since the last heartbeat.
+static LOOPS: AtomicU64 = AtomicU64::new(0);
+
+/// The total and longest time between loop iterations since the last heartbeat in microseconds.
+static TOTAL_LOOP_MICROS: AtomicU64 = AtomicU64::new(0);
+static MAX_LOOP_MICROS: AtomicU64 = AtomicU64::new(0);
+
+/// Starts printing a [heartbeat](self) over serial every `interval`.
+///
+/// Heartbeats are printed by the async executor, so they stop if the executor stops running (for
+/// example, if a task never yields). Calling this again changes the interval.
+///
+/// # Examples
+///
+/// ```no_run
+/// use core::time::Duration;
+/// use vexide::core::io;
+///
+/// io::enable_heartbeat(Duration::from_secs(1));
+/// ```
+pub fn enable_heartbeat(interval: Duration) {
+    let interval = (interval.as_micros() as u64).max(1);
+
+    if INTERVAL_MICROS.swap(interval, Ordering::Relaxed) == 0 {
+        LAST_HEARTBEAT.store(time::micros(), Ordering::Relaxed);
+        LAST_LOOP.store(u64::MAX, Ordering::Relaxed);
+        reset_stats();
+    }
+}
+
+/// Stops printing heartbeats over serial.
+pub fn disable_heartbeat() {
+    INTERVAL_MICROS.store(0, Ordering::Relaxed);
+}
+
+/// Returns the interval between heartbeats, or [`None`] if heartbeats are disabled.
+pub fn heartbeat_interval() -> Option<Duration> {
+    match INTERVAL_MICROS.load(Ordering::Relaxed) {
+        0 => None,
+        interval => Some(Duration::from_micros(interval)),
+    }
+}
+
+fn reset_stats() {
+    LOOPS.store(0, Ordering::Relaxed);
+    TOTAL_LOOP_MICROS.store(0, Ordering::Relaxed);
+    MAX_LOOP_MICROS.store(0, Ordering::Relaxed);
+}
+
+/// Records an iteration of the executor's loop, printing a heartbeat if one is due.
+///
+/// This is called by the executor and shouldn't be called from user code.
+#[doc(hidden)]
+pub fn __heartbeat_tick() {
+    let interval = INTERVAL_MICROS.load(Ordering::Relaxed);
+    if interval == 0 {
+        return;
+    }
+
+    let now = time::micros();
+
+    let last_loop = LAST_LOOP.swap(now, Ordering::Relaxed);
+    if last_loop != u64::MAX {
+        let loop_micros = now.saturating_sub(last_loop);
+
+        LOOPS.fetch_add(1, Ordering::Relaxed);
+        TOTAL_LOOP_MICROS.fetch_add(loop_micros, Ordering::Relaxed);
+        MAX_LOOP_MICROS.fetch_max(loop_micros, Ordering::Relaxed);
+    }
+
+    if now.saturating_sub(LAST_HEARTBEAT.load(Ordering::Relaxed)) < interval {
+        return;
+    }
+    LAST_HEARTBEAT.store(now, Ordering::Relaxed);
+
+    let loops = LOOPS.load(Ordering::Relaxed);
+    let average = TOTAL_LOOP_MICROS
+        .load(Ordering::Relaxed)
+        .checked_div(loops)
+        .unwrap_or_default();
+    let max = MAX_LOOP_MICROS.load(Ordering::Relaxed);
+    reset_stats();
+
+    // A heartbeat that can't be written is simply skipped, since it's only informational.
+    _ = stdout().lock().write_fmt(format_args!(
+        "[heartbeat] uptime_ms={} loops={loops} avg_loop_us={average} max_loop_us={max}\n",
+        now / 1000,
+    ));
+}
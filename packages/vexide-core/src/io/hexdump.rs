@@ -0,0 +1,90 @@
+//! Hex dumps for debugging binary data.
+//!
+//! When a binary protocol (such as one sent over generic serial or VEXLink) isn't behaving as
+//! expected, it often helps to look at the raw bytes. [`hexdump`] prints data in the classic
+//! offset/hex/ASCII format used by `hexdump -C`:
+//!
+//! ```text
+//! 00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 00 ff 0a  |Hello, world!...|
+//! 00000010
+//! ```
+
+use no_std_io::io::{self, Write};
+
+use super::stdout;
+
+/// The number of bytes shown on each line of a hex dump.
+const BYTES_PER_LINE: usize = 16;
+
+/// The column at which the ASCII section of a line begins.
+const ASCII_START: usize = 10 + BYTES_PER_LINE * 3 + 2;
+
+/// The length of a single formatted line, including its trailing newline.
+const LINE_LENGTH: usize = ASCII_START + 1 + BYTES_PER_LINE + 1 + 1;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Writes `value` as lowercase hexadecimal digits into `out`, padded with zeros to fill it.
+fn write_hex(out: &mut [u8], mut value: usize) {
+    for digit in out.iter_mut().rev() {
+        *digit = HEX_DIGITS[value & 0xF];
+        value >>= 4;
+    }
+}
+
+/// Writes a hex dump of `bytes` to `writer`.
+///
+/// Each line is formatted into a fixed-size buffer on the stack, so this doesn't allocate.
+pub fn write_hexdump(writer: &mut impl Write, bytes: &[u8]) -> io::Result<()> {
+    for (index, chunk) in bytes.chunks(BYTES_PER_LINE).enumerate() {
+        let mut line = [b' '; LINE_LENGTH];
+
+        write_hex(&mut line[..8], index * BYTES_PER_LINE);
+
+        for (column, &byte) in chunk.iter().enumerate() {
+            // Leave an extra space between the two groups of eight bytes.
+            let start = 10 + column * 3 + usize::from(column >= BYTES_PER_LINE / 2);
+            write_hex(&mut line[start..start + 2], byte.into());
+        }
+
+        line[ASCII_START] = b'|';
+        for (column, &byte) in chunk.iter().enumerate() {
+            line[ASCII_START + 1 + column] = if byte.is_ascii_graphic() || byte == b' ' {
+                byte
+            } else {
+                b'.'
+            };
+        }
+
+        let ascii_end = ASCII_START + 1 + chunk.len();
+        line[ascii_end] = b'|';
+        line[ascii_end + 1] = b'\n';
+
+        writer.write_all(&line[..ascii_end + 2])?;
+    }
+
+    // Finish with the total length, matching `hexdump -C`.
+    let mut end = [b'\n'; 9];
+    write_hex(&mut end[..8], bytes.len());
+    writer.write_all(&end)
+}
+
+/// Prints a hex dump of `bytes` to the standard output.
+///
+/// Each line shows the offset of its first byte, up to 16 bytes in hexadecimal, and the same bytes
+/// as ASCII, with non-printable characters replaced by `.`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide::core::io::hexdump;
+///
+/// let mut buf = [0; 64];
+/// let read = serial.read(&mut buf)?;
+/// hexdump(&buf[..read]);
+/// ```
+pub fn hexdump(bytes: &[u8]) {
+    if let Err(e) = write_hexdump(&mut stdout().lock(), bytes) {
+        panic!("failed printing to stdout: {e}");
+    }
+}
@@ -0,0 +1,358 @@
+//! Leveled logging over the serial terminal.
+//!
+//! The [`error!`], [`warn!`], [`info!`], and [`debug!`] macros behave like [`println!`](crate::io::println),
+//! but prefix each message with its level and the time since the program started. Messages below
+//! the level set by [`set_log_level`] are discarded, which can be used to silence debug output during
+//! matches.
+//!
+//! When [ANSI colors](set_ansi_enabled) are enabled, errors are printed in red and warnings in yellow.
+//! Colors are disabled by default, since not every serial terminal supports them.
+//!
+//! # Logging to the Display
+//!
+//! By default, messages are only printed over serial. [`set_log_sink`] can also (or instead) route
+//! messages to the brain's display, which is useful for diagnosing problems during a match when no
+//! computer is attached to the robot. New messages are added to the bottom of the
+//! [log region](set_log_display_region), scrolling older messages up and out of view.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use vexide::core::io::{info, debug, set_log_level, LogLevel};
+//!
+//! info!("Starting autonomous");
+//!
+//! set_log_level(LogLevel::Info);
+//! debug!("This will not be printed");
+//! ```
+
+use core::{
+    ffi::c_int,
+    fmt::{self, Write as _},
+    sync::atomic::{AtomicBool, AtomicI16, AtomicU8, Ordering},
+};
+
+use no_std_io::io::Write;
+use vex_sdk::{vexDisplayForegroundColor, vexDisplayScrollRect, vexDisplayStringAt};
+
+use super::{stdout, ArrayString};
+use crate::time;
+
+/// The importance of a log message.
+///
+/// Levels are ordered from most to least important, so [`LogLevel::Error`] is less than
+/// [`LogLevel::Debug`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum LogLevel {
+    /// A serious failure that prevents the program from working as intended.
+    Error = 0,
+
+    /// A potential problem that doesn't prevent the program from continuing.
+    Warn = 1,
+
+    /// General information about the program's progress.
+    Info = 2,
+
+    /// Verbose information that is only useful while debugging.
+    Debug = 3,
+}
+
+impl LogLevel {
+    /// Returns the label printed before messages of this level.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "ERROR",
+            Self::Warn => "WARN",
+            Self::Info => "INFO",
+            Self::Debug => "DEBUG",
+        }
+    }
+
+    /// Returns the ANSI escape code used to color messages of this level, if any.
+    const fn ansi_color(&self) -> Option<&'static str> {
+        match self {
+            Self::Error => Some("\x1b[31m"),
+            Self::Warn => Some("\x1b[33m"),
+            Self::Info | Self::Debug => None,
+        }
+    }
+
+    /// Returns the color used to draw messages of this level on the display.
+    const fn display_color(&self) -> u32 {
+        match self {
+            Self::Error => 0xFF0000,
+            Self::Warn => 0xFFFF00,
+            Self::Info | Self::Debug => 0xFFFFFF,
+        }
+    }
+
+    const fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Error,
+            1 => Self::Warn,
+            2 => Self::Info,
+            _ => Self::Debug,
+        }
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// Where log messages are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum LogSink {
+    /// Messages are printed to the serial terminal.
+    Serial = 0,
+
+    /// Messages are drawn on the brain's display.
+    Display = 1,
+
+    /// Messages are printed to the serial terminal and drawn on the brain's display.
+    Both = 2,
+}
+
+impl LogSink {
+    /// Returns `true` if messages are printed to the serial terminal.
+    pub const fn includes_serial(&self) -> bool {
+        matches!(self, Self::Serial | Self::Both)
+    }
+
+    /// Returns `true` if messages are drawn on the brain's display.
+    pub const fn includes_display(&self) -> bool {
+        matches!(self, Self::Display | Self::Both)
+    }
+
+    const fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Serial,
+            1 => Self::Display,
+            _ => Self::Both,
+        }
+    }
+}
+
+static LOG_LEVEL: AtomicU8 = AtomicU8::new(LogLevel::Debug as u8);
+static ANSI_ENABLED: AtomicBool = AtomicBool::new(false);
+static LOG_SINK: AtomicU8 = AtomicU8::new(LogSink::Serial as u8);
+static DISPLAY_REGION_TOP: AtomicI16 = AtomicI16::new(0);
+static DISPLAY_REGION_BOTTOM: AtomicI16 = AtomicI16::new(DISPLAY_HEIGHT);
+
+/// The height of the program header at the top of the display, which can't be drawn over.
+const DISPLAY_HEADER_HEIGHT: i16 = 32;
+
+/// The height of the part of the display below the program header.
+const DISPLAY_HEIGHT: i16 = 240;
+
+/// The width of the display.
+const DISPLAY_WIDTH: i16 = 480;
+
+/// The height of a line of log messages on the display.
+const DISPLAY_LINE_HEIGHT: i16 = 20;
+
+/// The longest message that will be drawn on the display, in bytes.
+///
+/// Longer messages are truncated. This is more than fits on a single line of the display anyway.
+const DISPLAY_MESSAGE_CAPACITY: usize = 128;
+
+/// The ANSI escape code that resets text back to the terminal's default style.
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Enables or disables ANSI color codes in log output.
+///
+/// This is disabled by default, since terminals without ANSI support will display the escape
+/// codes as garbage characters.
+pub fn set_ansi_enabled(enabled: bool) {
+    ANSI_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns `true` if log output will be colored using ANSI escape codes.
+pub fn ansi_enabled() -> bool {
+    ANSI_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Sets the least important level of messages that will be printed.
+///
+/// All levels are printed by default.
+pub fn set_log_level(level: LogLevel) {
+    LOG_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Returns the least important level of messages that will be printed.
+pub fn log_level() -> LogLevel {
+    LogLevel::from_u8(LOG_LEVEL.load(Ordering::Relaxed))
+}
+
+/// Sets where log messages are written.
+///
+/// Messages are only printed to the serial terminal by default.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide::core::io::{set_log_sink, LogSink};
+///
+/// // Show logs on the brain's screen while still printing them over serial.
+/// set_log_sink(LogSink::Both);
+/// ```
+pub fn set_log_sink(sink: LogSink) {
+    LOG_SINK.store(sink as u8, Ordering::Relaxed);
+}
+
+/// Returns where log messages are written.
+pub fn log_sink() -> LogSink {
+    LogSink::from_u8(LOG_SINK.load(Ordering::Relaxed))
+}
+
+/// Sets the rows of the display that log messages are drawn in when logging to the
+/// [display](LogSink::Display).
+///
+/// `top` and `bottom` are y-coordinates measured from the top of the drawable area of the display
+/// (below the program header), and are clamped to the display's height of 240 pixels. Each
+/// message takes up 20 pixels, so the region should be at least that tall. Anything drawn in the
+/// region by other code will be scrolled away by new messages.
+///
+/// The region covers the entire display by default.
+pub fn set_log_display_region(top: i16, bottom: i16) {
+    let top = top.clamp(0, DISPLAY_HEIGHT);
+    DISPLAY_REGION_TOP.store(top, Ordering::Relaxed);
+    DISPLAY_REGION_BOTTOM.store(bottom.clamp(top, DISPLAY_HEIGHT), Ordering::Relaxed);
+}
+
+/// Returns the `(top, bottom)` rows of the display that log messages are drawn in.
+///
+/// See [`set_log_display_region`] for more information.
+pub fn log_display_region() -> (i16, i16) {
+    (
+        DISPLAY_REGION_TOP.load(Ordering::Relaxed),
+        DISPLAY_REGION_BOTTOM.load(Ordering::Relaxed),
+    )
+}
+
+/// Draws a line of text at the bottom of the log region, scrolling the region's existing contents
+/// up to make room for it.
+fn draw_display_line(level: LogLevel, line: &str) {
+    let (top, bottom) = log_display_region();
+    if bottom - top < DISPLAY_LINE_HEIGHT {
+        return;
+    }
+
+    unsafe {
+        vexDisplayScrollRect(
+            0,
+            (top + DISPLAY_HEADER_HEIGHT).into(),
+            (DISPLAY_WIDTH - 1).into(),
+            (bottom + DISPLAY_HEADER_HEIGHT - 1).into(),
+            DISPLAY_LINE_HEIGHT.into(),
+        );
+
+        vexDisplayForegroundColor(level.display_color());
+        vexDisplayStringAt(
+            0,
+            (bottom - DISPLAY_LINE_HEIGHT + DISPLAY_HEADER_HEIGHT).into(),
+            c"%.*s".as_ptr(),
+            line.len() as c_int,
+            line.as_ptr(),
+        );
+    }
+}
+
+/// Returns `true` if messages of the given level will be printed.
+pub fn log_enabled(level: LogLevel) -> bool {
+    level <= log_level()
+}
+
+#[doc(hidden)]
+pub fn __log(level: LogLevel, args: fmt::Arguments<'_>) {
+    if !log_enabled(level) {
+        return;
+    }
+
+    let micros = time::micros();
+    let sink = log_sink();
+
+    if sink.includes_serial() {
+        let (color, reset) = match level.ansi_color() {
+            Some(color) if ansi_enabled() => (color, ANSI_RESET),
+            _ => ("", ""),
+        };
+
+        if let Err(e) = stdout().lock().write_fmt(format_args!(
+            "{color}[{:>5}.{:03}s {:<5}] {}{reset}\n",
+            micros / 1_000_000,
+            (micros / 1000) % 1000,
+            level,
+            args
+        )) {
+            panic!("failed printing to stdout: {e}");
+        }
+    }
+
+    if sink.includes_display() {
+        // Messages are formatted into a fixed-size buffer so that logging to the display doesn't
+        // allocate. Anything that doesn't fit is cut off, so the error can be ignored.
+        let mut message = ArrayString::<DISPLAY_MESSAGE_CAPACITY>::new();
+        _ = write!(
+            message,
+            "[{}.{:03}s {}] {}",
+            micros / 1_000_000,
+            (micros / 1000) % 1000,
+            level,
+            args
+        );
+
+        for line in message.lines() {
+            draw_display_line(level, line);
+        }
+    }
+}
+
+#[macro_export]
+/// Prints a message to the standard output at the given [`LogLevel`].
+macro_rules! log {
+    ($level:expr, $($arg:tt)*) => {
+        $crate::io::__log($level, format_args!($($arg)*))
+    };
+}
+pub use log;
+
+#[macro_export]
+/// Prints a message to the standard output at the [`Error`](LogLevel::Error) level.
+macro_rules! error {
+    ($($arg:tt)*) => {
+        $crate::log!($crate::io::LogLevel::Error, $($arg)*)
+    };
+}
+pub use error;
+
+#[macro_export]
+/// Prints a message to the standard output at the [`Warn`](LogLevel::Warn) level.
+macro_rules! warn {
+    ($($arg:tt)*) => {
+        $crate::log!($crate::io::LogLevel::Warn, $($arg)*)
+    };
+}
+pub use warn;
+
+#[macro_export]
+/// Prints a message to the standard output at the [`Info`](LogLevel::Info) level.
+macro_rules! info {
+    ($($arg:tt)*) => {
+        $crate::log!($crate::io::LogLevel::Info, $($arg)*)
+    };
+}
+pub use info;
+
+#[macro_export]
+/// Prints a message to the standard output at the [`Debug`](LogLevel::Debug) level.
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        $crate::log!($crate::io::LogLevel::Debug, $($arg)*)
+    };
+}
+pub use debug;
@@ -1,10 +1,45 @@
 //! Serial input and output functionality.
 //!
 //! This module aims to provide a very similar API to the Rust standard library's `std::io` module.
+//!
+//! # Serial Configuration
+//!
+//! The serial terminal is a USB CDC (virtual serial port) connection to the brain or controller, so
+//! it has no real baud rate. Data is sent as fast as the USB link and VEXos allow, and the baud rate
+//! set by the program on the other end is ignored, so any value will work. This also means that the
+//! baud rate can't be configured from the brain.
+//!
+//! VEXos doesn't add any framing to the data sent over the terminal. To exchange structured messages
+//! with external tools such as dashboards or scripts, wrap [`stdio`] in a [`Framed`] stream, which
+//! splits the byte stream into frames and can optionally checksum each one.
 
+mod array_string;
+mod framing;
+mod heartbeat;
+mod hexdump;
+mod log;
+mod rate_limit;
+mod record;
 mod stdio;
 
+pub use array_string::ArrayString;
+pub use framing::{cobs_decode, cobs_encode, crc16, Framed};
+#[doc(hidden)]
+pub use heartbeat::__heartbeat_tick;
+pub use heartbeat::{disable_heartbeat, enable_heartbeat, heartbeat_interval};
+pub use hexdump::{hexdump, write_hexdump};
 #[doc(inline)]
 pub use no_std_io::io::*;
+#[doc(hidden)]
+pub use log::__log;
+pub use log::{
+    ansi_enabled, debug, error, info, log, log_display_region, log_enabled, log_level, log_sink,
+    set_ansi_enabled, set_log_display_region, set_log_level, set_log_sink, warn, LogLevel,
+    LogSink,
+};
+pub use rate_limit::{print_every, println_every, RateLimiter};
+pub use record::{Recorder, Replay, Sample};
 pub(crate) use stdio::STDIO_CHANNEL;
-pub use stdio::{dbg, print, println, stdin, stdout, Stdin, StdinLock, Stdout, StdoutLock};
+pub use stdio::{
+    dbg, print, println, stdin, stdio, stdout, Stdin, StdinLock, Stdio, Stdout, StdoutLock,
+};
@@ -0,0 +1,98 @@
+//! Rate limiting for serial output.
+//!
+//! Printing inside of a tight loop can easily flood the serial link faster than it can be
+//! flushed, which slows down the rest of the program. [`RateLimiter`] and the [`println_every!`]
+//! macro drop messages that arrive faster than a configured interval.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use vexide::core::io::println_every;
+//!
+//! loop {
+//!     // Only prints at most once every 500ms, even though the loop runs every 10ms.
+//!     println_every!(Duration::from_millis(500), "Running control loop");
+//!     sleep(Duration::from_millis(10)).await;
+//! }
+//! ```
+
+use core::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use crate::time;
+
+/// Allows an action to run at most once per interval.
+///
+/// This type uses interior mutability, so it can be stored in a `static` and shared between tasks.
+#[derive(Debug)]
+pub struct RateLimiter {
+    interval: Duration,
+    /// The time of the last permitted action in microseconds, or [`u64::MAX`] if there hasn't been one.
+    last: AtomicU64,
+}
+
+impl RateLimiter {
+    /// Creates a new rate limiter that permits one action per `interval`.
+    pub const fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last: AtomicU64::new(u64::MAX),
+        }
+    }
+
+    /// Returns the minimum amount of time between permitted actions.
+    pub const fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Returns `true` if at least one interval has passed since this last returned `true`.
+    ///
+    /// The first call to this function always returns `true`.
+    pub fn ready(&self) -> bool {
+        let now = time::micros();
+        let last = self.last.load(Ordering::Relaxed);
+
+        if last == u64::MAX || now.saturating_sub(last) >= self.interval.as_micros() as u64 {
+            self.last.store(now, Ordering::Relaxed);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Forgets the last permitted action, allowing the next call to [`RateLimiter::ready`] to succeed.
+    pub fn reset(&self) {
+        self.last.store(u64::MAX, Ordering::Relaxed);
+    }
+}
+
+#[macro_export]
+/// Prints a message to the standard output, dropping it if the same call site has already printed
+/// within the given interval.
+///
+/// The interval must be a constant expression, such as [`Duration::from_millis`](core::time::Duration::from_millis).
+macro_rules! print_every {
+    ($interval:expr, $($arg:tt)*) => {{
+        static LIMITER: $crate::io::RateLimiter = $crate::io::RateLimiter::new($interval);
+        if LIMITER.ready() {
+            $crate::print!($($arg)*);
+        }
+    }};
+}
+pub use print_every;
+
+#[macro_export]
+/// Prints a message to the standard output and appends a newline, dropping it if the same call site
+/// has already printed within the given interval.
+macro_rules! println_every {
+    ($interval:expr) => {
+        $crate::print_every!($interval, "\n")
+    };
+    ($interval:expr, $($arg:tt)*) => {
+        $crate::print_every!($interval, "{}\n", format_args!($($arg)*))
+    };
+}
+pub use println_every;
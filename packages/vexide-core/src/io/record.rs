@@ -0,0 +1,201 @@
+//! Recording and replaying sensor readings.
+//!
+//! When an autonomous routine misbehaves on the field, it's often hard to tell what the robot's
+//! sensors were reporting at the time. [`Recorder`] writes timestamped readings to any
+//! [`Write`]r (usually a [`File`](crate::fs::File) on the SD card) as they are taken, and [`Replay`]
+//! reads a recording back so that the same readings can be fed through the program's logic again.
+//!
+//! # Format
+//!
+//! Recordings are plain text, with one reading per line in the form `micros,channel,value`:
+//!
+//! ```text
+//! 0,left_position,0
+//! 10021,left_position,0.0125
+//! 10034,heading,359.87
+//! ```
+//!
+//! `micros` is the time in microseconds since the recorder was created, `channel` is a name
+//! identifying the reading, and `value` is the reading itself. Since this is valid CSV, recordings
+//! can also be opened in a spreadsheet or plotting tool.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use vexide::core::{fs::File, io::{Recorder, Replay}};
+//!
+//! // During a run:
+//! let mut recorder = Recorder::new(File::create("run.csv")?);
+//! loop {
+//!     recorder.record("heading", imu.heading()?)?;
+//!     recorder.record("left", left.position()?.as_revolutions())?;
+//!     sleep(Duration::from_millis(10)).await;
+//! }
+//!
+//! // Later, feed the recorded readings back through the same logic:
+//! let contents = vexide::core::fs::read_to_string("run.csv")?;
+//! let replay = Replay::new(&contents)?;
+//! for sample in replay.samples() {
+//!     if sample.channel == "heading" {
+//!         update_heading(sample.value);
+//!     }
+//! }
+//! ```
+
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use no_std_io::io::{self, Write};
+
+use crate::time::Instant;
+
+/// Writes timestamped readings to a stream.
+///
+/// See the [module-level documentation](self) for the format of the recording.
+#[derive(Debug)]
+pub struct Recorder<W> {
+    writer: W,
+    start: Instant,
+}
+
+impl<W: Write> Recorder<W> {
+    /// Creates a new recorder that writes to `writer`.
+    ///
+    /// Timestamps are measured from when this is called.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            start: Instant::now(),
+        }
+    }
+
+    /// Records a reading on the given channel, timestamped with the current time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidInput`] if `channel` contains a comma or a
+    /// newline, since it couldn't be read back. Also returns any error from the underlying writer.
+    pub fn record(&mut self, channel: &str, value: f64) -> io::Result<()> {
+        if channel.contains([',', '\n', '\r']) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Channel names can't contain commas or newlines.",
+            ));
+        }
+
+        let micros = self.start.elapsed().as_micros();
+        writeln!(self.writer, "{micros},{channel},{value}")
+    }
+
+    /// Flushes any buffered readings to the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Consumes the recorder, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// A single reading from a recording.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sample<'a> {
+    /// The time the reading was taken, relative to the start of the recording.
+    pub time: Duration,
+
+    /// The name of the reading.
+    pub channel: &'a str,
+
+    /// The value of the reading.
+    pub value: f64,
+}
+
+impl<'a> Sample<'a> {
+    /// Parses a single line of a recording.
+    fn parse(line: &'a str) -> Option<Self> {
+        let mut fields = line.splitn(3, ',');
+
+        let micros = fields.next()?.trim().parse::<u64>().ok()?;
+        let channel = fields.next()?;
+        let value = fields.next()?.trim().parse::<f64>().ok()?;
+
+        Some(Self {
+            time: Duration::from_micros(micros),
+            channel,
+            value,
+        })
+    }
+}
+
+/// Readings loaded from a recording made by a [`Recorder`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Replay<'a> {
+    samples: Vec<Sample<'a>>,
+}
+
+impl<'a> Replay<'a> {
+    /// Parses the contents of a recording.
+    ///
+    /// Empty lines are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] if any line isn't a valid reading.
+    pub fn new(contents: &'a str) -> io::Result<Self> {
+        let mut samples = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                Sample::parse(line).ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "Malformed recording line.")
+                })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        // Readings are written in order, but sort them anyway in case recordings were combined.
+        samples.sort_by_key(|sample| sample.time);
+
+        Ok(Self { samples })
+    }
+
+    /// Returns every reading in the recording in the order they were taken.
+    pub fn samples(&self) -> &[Sample<'a>] {
+        &self.samples
+    }
+
+    /// Returns every reading on the given channel in the order they were taken.
+    pub fn channel<'s>(&'s self, channel: &'s str) -> impl Iterator<Item = Sample<'a>> + 's {
+        self.samples
+            .iter()
+            .copied()
+            .filter(move |sample| sample.channel == channel)
+    }
+
+    /// Returns the most recent value recorded on `channel` at or before `time`, or [`None`] if no
+    /// reading had been taken on that channel yet.
+    ///
+    /// This can be used to drive logic from the recording as if the readings were coming from live
+    /// sensors, by passing in the time elapsed since the replay started.
+    pub fn value_at(&self, channel: &str, time: Duration) -> Option<f64> {
+        let end = self.samples.partition_point(|sample| sample.time <= time);
+
+        self.samples[..end]
+            .iter()
+            .rev()
+            .find(|sample| sample.channel == channel)
+            .map(|sample| sample.value)
+    }
+
+    /// Returns the time of the last reading in the recording.
+    pub fn duration(&self) -> Duration {
+        self.samples
+            .last()
+            .map_or(Duration::ZERO, |sample| sample.time)
+    }
+}
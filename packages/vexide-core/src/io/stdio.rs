@@ -150,6 +150,42 @@ pub const fn stdin() -> Stdin {
     Stdin
 }
 
+/// A handle to both the serial input and output streams of this program.
+///
+/// This is useful for wrapping the serial terminal in types that need to both read and write, such
+/// as [`Framed`](super::Framed), when communicating with external tools over a custom protocol.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide::core::io::{self, Framed};
+///
+/// let mut link = Framed::new(io::stdio());
+/// link.write_frame_checked(b"hello, dashboard")?;
+/// ```
+pub struct Stdio;
+
+/// Constructs a handle to the serial input and output streams.
+pub const fn stdio() -> Stdio {
+    Stdio
+}
+
+impl io::Read for Stdio {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        io::Read::read(&mut stdin().lock(), buf)
+    }
+}
+
+impl Write for Stdio {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        stdout().lock().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        stdout().lock().flush()
+    }
+}
+
 #[macro_export]
 /// Prints a message to the standard output and appends a newline.
 macro_rules! println {
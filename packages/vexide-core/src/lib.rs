@@ -5,12 +5,14 @@
 //! - Global allocator: [`allocator`]
 //! - Competition state handling: [`competition`]
 //! - Serial terminal printing: [`io`]
+//! - SD card file access: [`fs`]
 //! - No-std [`Instant`](time::Instant)s: [`time`]
 //! - Synchronization primitives: [`sync`]
 //! - Program control: [`program`]
+//! - Tuning parameters from the SD card: [`tuning`]
 
 #![no_std]
-#![feature(never_type)]
+#![feature(never_type, allocator_api)]
 
 extern crate alloc;
 
@@ -18,7 +20,9 @@ pub mod allocator;
 pub mod backtrace;
 pub mod competition;
 pub mod float;
+pub mod fs;
 pub mod io;
 pub mod program;
 pub mod sync;
 pub mod time;
+pub mod tuning;
@@ -1,11 +1,29 @@
 //! Functions for modifying the state of the current
 //! user program.
 
-use core::{convert::Infallible, fmt::Debug, time::Duration};
+use alloc::vec::Vec;
+use core::{
+    convert::Infallible,
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    task::{Context, Poll},
+    time::Duration,
+};
 
-use vex_sdk::{vexSerialWriteFree, vexSystemExitRequest, vexTasksRun};
+use vex_sdk::{
+    vexDeviceAdiPortConfigGet, vexDeviceAdiValueSet, vexDeviceGetByIndex, vexDeviceGetStatus,
+    vexDeviceMotorBrakeModeSet, vexDeviceMotorVelocitySet, vexSerialWriteFree,
+    vexSystemExitRequest, vexTasksRun, V5MotorBrakeMode, V5_AdiPortConfiguration, V5_DeviceType,
+    V5_MAX_DEVICE_PORTS,
+};
 
-use crate::{io, time::Instant};
+use crate::{
+    io,
+    sync::Mutex,
+    time::{self, Instant},
+};
 
 /// A that can be implemented for arbitrary return types in the main function.
 pub trait Termination {
@@ -31,21 +49,105 @@ impl<T: Termination, E: Debug> Termination for Result<T, E> {
     }
 }
 
+/// A list of functions to run once when the program exits.
+struct ExitHooks {
+    /// The registered hooks, in the order they were registered.
+    hooks: Mutex<Vec<fn()>>,
+    /// Set once the hooks start running, so that a hook calling [`exit`] doesn't run them again.
+    ran: AtomicBool,
+}
+
+impl ExitHooks {
+    const fn new() -> Self {
+        Self {
+            hooks: Mutex::new(Vec::new()),
+            ran: AtomicBool::new(false),
+        }
+    }
+
+    fn register(&self, hook: fn()) {
+        self.hooks.lock_blocking().push(hook);
+    }
+
+    /// Calls `before`, then runs every registered hook, most recently registered first.
+    ///
+    /// Returns `false` without running anything if the hooks have already been run.
+    fn run(&self, before: impl FnOnce()) -> bool {
+        if self.ran.swap(true, Ordering::Relaxed) {
+            return false;
+        }
+
+        before();
+
+        // Take the hooks out of the mutex first, so that hooks can't deadlock by registering
+        // more hooks.
+        let hooks = core::mem::take(&mut *self.hooks.lock_blocking());
+        for hook in hooks.into_iter().rev() {
+            hook();
+        }
+
+        true
+    }
+}
+
+/// The functions registered with [`on_exit`].
+static EXIT_HOOKS: ExitHooks = ExitHooks::new();
+
+/// Registers a function to be called when the program exits through [`exit`].
+///
+/// Exit hooks run in the reverse order of their registration, after every motor has been
+/// [stopped](stop_all_motors) and before serial output is flushed. This is useful for saving state
+/// to the SD card or releasing resources before the program ends.
+///
+/// Exit hooks don't run if the program is stopped by VEXos (such as from the brain's screen or the
+/// controller), since the program isn't given a chance to respond.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide::core::program;
+///
+/// program::on_exit(|| {
+///     println!("Goodbye!");
+/// });
+///
+/// program::exit();
+/// ```
+pub fn on_exit(hook: fn()) {
+    EXIT_HOOKS.register(hook);
+}
+
 /// Exits the program using vexSystemExitRequest.
-/// This function will not instantly exit the program,
-/// but will instead wait 3ms to force the serial buffer to flush.
+///
+/// Before exiting, this cleans up after the program:
+///
+/// 1. Every motor plugged into the brain is [stopped](stop_all_motors), so that nothing keeps
+///    driving while the rest of the cleanup runs.
+/// 2. Every function registered with [`on_exit`] is called, most recently registered first.
+/// 3. The program waits up to 15ms for the serial buffer to flush, so that output printed just
+///    before exiting isn't lost.
+///
+/// If an exit hook calls this function, the remaining cleanup steps are skipped and the program
+/// exits immediately.
 pub fn exit() -> ! {
-    let exit_time = Instant::now();
-    const FLUSH_TIMEOUT: Duration = Duration::from_millis(15);
-    unsafe {
-        // Force the serial buffer to flush
-        while exit_time.elapsed() < FLUSH_TIMEOUT {
-            // If the buffer has been fully flushed, exit the loop
-            if vexSerialWriteFree(io::STDIO_CHANNEL) == (io::Stdout::INTERNAL_BUFFER_SIZE as i32) {
-                break;
+    if EXIT_HOOKS.run(stop_all_motors) {
+        let exit_time = Instant::now();
+        const FLUSH_TIMEOUT: Duration = Duration::from_millis(15);
+        unsafe {
+            // Force the serial buffer to flush
+            while exit_time.elapsed() < FLUSH_TIMEOUT {
+                // If the buffer has been fully flushed, exit the loop
+                if vexSerialWriteFree(io::STDIO_CHANNEL)
+                    == (io::Stdout::INTERNAL_BUFFER_SIZE as i32)
+                {
+                    break;
+                }
+                vexTasksRun();
             }
-            vexTasksRun();
         }
+    }
+
+    unsafe {
         // Exit the program
         // Everything after this point is unreachable.
         vexSystemExitRequest();
@@ -56,3 +158,164 @@ pub fn exit() -> ! {
         core::hint::spin_loop();
     }
 }
+
+/// The device index of the brain's onboard ADI ports.
+const INTERNAL_ADI_INDEX: usize = 21;
+
+/// Stops every motor plugged into the brain.
+///
+/// This scans every device connected to the brain, regardless of whether a device has been created
+/// for it:
+///
+/// - Smart motors are braked by holding a velocity of zero.
+/// - Legacy motors on the brain's onboard ADI ports or on an ADI expander have their output set to
+///   zero. Other ADI outputs, such as servos and solenoids, are left unchanged, since driving them
+///   to a default value could move the mechanism attached to them.
+///
+/// This is a best-effort operation intended for safety features that need to stop the robot, such
+/// as [`Watchdog`] and [`exit`], so it does not report errors for individual ports. It also doesn't
+/// update the target of any existing motor, so a task that keeps setting its motors will start
+/// them again.
+pub fn stop_all_motors() {
+    let mut device_types: [V5_DeviceType; V5_MAX_DEVICE_PORTS] = unsafe { core::mem::zeroed() };
+    unsafe {
+        vexDeviceGetStatus(device_types.as_mut_ptr());
+    }
+
+    for (index, device_type) in device_types.iter().enumerate() {
+        if *device_type == V5_DeviceType::kDeviceTypeMotorSensor {
+            unsafe {
+                let device = vexDeviceGetByIndex(index as u32);
+
+                vexDeviceMotorBrakeModeSet(device, V5MotorBrakeMode::kV5MotorBrakeModeBrake);
+                // Force the motor into braking by putting it into velocity control with a 0rpm setpoint.
+                vexDeviceMotorVelocitySet(device, 0);
+            }
+        } else if index == INTERNAL_ADI_INDEX || *device_type == V5_DeviceType::kDeviceTypeAdiSensor
+        {
+            unsafe {
+                let device = vexDeviceGetByIndex(index as u32);
+
+                for port in 0..8 {
+                    if vexDeviceAdiPortConfigGet(device, port)
+                        == V5_AdiPortConfiguration::kAdiPortTypeLegacyPwm
+                    {
+                        vexDeviceAdiValueSet(device, port, 0);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A safety timer that stops the robot if a control loop stalls.
+///
+/// A watchdog must be [fed](Watchdog::feed) periodically by the code it is protecting. If it goes
+/// longer than its timeout without being fed, every motor plugged into the brain is stopped.
+///
+/// The watchdog is checked by the future returned from [`Watchdog::watch`], which should be spawned
+/// as its own task. Because the executor is cooperative, this can only catch stalls where the control
+/// loop is still yielding to the executor (for example, a task waiting on a deadlocked mutex). A loop
+/// that spins forever without ever awaiting will also prevent the watchdog from running.
+///
+/// # Choosing a Timeout
+///
+/// The timeout should be a few times longer than the period of the loop being watched, so that an
+/// occasional slow iteration doesn't trip the watchdog. Three to five loop periods is a reasonable
+/// starting point (e.g. 50ms for a loop running every 10ms).
+///
+/// # Examples
+///
+/// ```no_run
+/// use core::time::Duration;
+/// use vexide::core::program::Watchdog;
+///
+/// static WATCHDOG: Watchdog = Watchdog::new(Duration::from_millis(50));
+///
+/// spawn(WATCHDOG.watch()).detach();
+///
+/// loop {
+///     WATCHDOG.feed();
+///     // Control loop code here...
+///     sleep(Duration::from_millis(10)).await;
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Watchdog {
+    timeout: Duration,
+    /// The time the watchdog was last fed in microseconds, or [`u64::MAX`] if it hasn't been yet.
+    last_fed: AtomicU64,
+    tripped: AtomicBool,
+}
+
+impl Watchdog {
+    /// Creates a new watchdog that trips if it isn't fed at least once every `timeout`.
+    pub const fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            last_fed: AtomicU64::new(u64::MAX),
+            tripped: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the maximum time allowed between feedings.
+    pub const fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Resets the watchdog's timer, signaling that the protected code is still running.
+    ///
+    /// Feeding a watchdog that has already tripped re-arms it.
+    pub fn feed(&self) {
+        self.last_fed.store(time::micros(), Ordering::Relaxed);
+        self.tripped.store(false, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the watchdog has tripped since it was last fed.
+    pub fn is_tripped(&self) -> bool {
+        self.tripped.load(Ordering::Relaxed)
+    }
+
+    /// Returns a future that monitors the watchdog, stopping all motors and resolving if it isn't
+    /// fed in time.
+    ///
+    /// The timer starts when the future is first polled if the watchdog hasn't been fed yet.
+    pub const fn watch(&self) -> WatchdogFuture<'_> {
+        WatchdogFuture { watchdog: self }
+    }
+}
+
+/// A future that stops all motors and resolves once a [`Watchdog`] trips.
+///
+/// See [`Watchdog::watch`] for more information.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WatchdogFuture<'a> {
+    watchdog: &'a Watchdog,
+}
+
+impl Future for WatchdogFuture<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let now = time::micros();
+
+        let last_fed = match self.watchdog.last_fed.load(Ordering::Relaxed) {
+            u64::MAX => {
+                self.watchdog.last_fed.store(now, Ordering::Relaxed);
+                now
+            }
+            last_fed => last_fed,
+        };
+
+        if now.saturating_sub(last_fed) > self.watchdog.timeout.as_micros() as u64 {
+            stop_all_motors();
+            self.watchdog.tripped.store(true, Ordering::Relaxed);
+
+            return Poll::Ready(());
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
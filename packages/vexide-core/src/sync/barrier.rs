@@ -1,53 +1,88 @@
 use core::{
     fmt::Debug,
+    pin::Pin,
     sync::atomic::{AtomicUsize, Ordering},
+    task::{Context, Poll},
 };
 
 use futures_core::Future;
 
 /// A future that resolves once all tasks have arrived at a [`Barrier`].
 /// This is created by [`Barrier::wait`].
+///
+/// A task only counts as having arrived at the barrier once this future is first polled.
+#[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct BarrierWaitFuture<'a> {
-    leader: bool,
     barrier: &'a Barrier,
+    /// The generation of the barrier that this task arrived during, or `None` if it hasn't
+    /// arrived yet.
+    generation: Option<usize>,
 }
+
 impl Future for BarrierWaitFuture<'_> {
     type Output = bool;
-    fn poll(
-        self: core::pin::Pin<&mut Self>,
-        cx: &mut core::task::Context<'_>,
-    ) -> core::task::Poll<Self::Output> {
-        if self
-            .barrier
-            .current
-            .load(core::sync::atomic::Ordering::Acquire)
-            == self.barrier.count
-        {
-            core::task::Poll::Ready(self.leader)
-        } else {
-            cx.waker().wake_by_ref();
-            core::task::Poll::Pending
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let barrier = self.barrier;
+        let current_generation = barrier.generation.load(Ordering::Acquire);
+
+        match self.generation {
+            None => {
+                let arrived = barrier.arrived.fetch_add(1, Ordering::AcqRel) + 1;
+
+                if arrived >= barrier.count {
+                    // This is the last task to arrive, so release everyone waiting on this
+                    // generation and reset the barrier for the next one.
+                    barrier.arrived.store(0, Ordering::Release);
+                    barrier
+                        .generation
+                        .store(current_generation.wrapping_add(1), Ordering::Release);
+
+                    return Poll::Ready(true);
+                }
+
+                self.generation = Some(current_generation);
+            }
+            Some(generation) if generation != current_generation => return Poll::Ready(false),
+            Some(_) => {}
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+impl Drop for BarrierWaitFuture<'_> {
+    /// Removes this task from the barrier if it stopped waiting before being released.
+    fn drop(&mut self) {
+        if self.generation == Some(self.barrier.generation.load(Ordering::Acquire)) {
+            self.barrier.arrived.fetch_sub(1, Ordering::AcqRel);
         }
     }
 }
 
 /// Allows for multiple tasks to reach the same point in execution before continuing.
 ///
+/// A barrier is reusable: once all tasks have been released, the next `count` calls to
+/// [`Barrier::wait`] wait for each other again.
+///
 /// # Examples
 ///
 /// ```rust
 /// const N: usize = 10;
-/// let handles = Vec::new();
-/// let barrier = Barrier::new(N);
-/// for i in 0..N {
-///     spawn(async {
-///         // Every "Before barrier" will be printed before any "After Barrier".
-///         println!("Before Barrier");
-///         barrier.wait().await;
-///         println!("After Barrier");
+/// static BARRIER: Barrier = Barrier::new(N);
+///
+/// let handles = (0..N)
+///     .map(|_| {
+///         spawn(async {
+///             // Every "Before barrier" will be printed before any "After Barrier".
+///             println!("Before Barrier");
+///             BARRIER.wait().await;
+///             println!("After Barrier");
+///         })
 ///     })
-/// }
+///     .collect::<Vec<_>>();
 ///
 /// for handle in handles {
 ///     handle.await;
@@ -55,33 +90,38 @@ impl Future for BarrierWaitFuture<'_> {
 /// ```
 pub struct Barrier {
     count: usize,
-    current: AtomicUsize,
+    /// The number of tasks that have arrived during the current generation.
+    arrived: AtomicUsize,
+    /// Incremented every time the barrier releases its waiting tasks.
+    generation: AtomicUsize,
 }
+
 impl Barrier {
-    /// Create a new barrier that will block `count` threads before releasing.
+    /// Create a new barrier that will block `count` tasks before releasing.
+    ///
+    /// A barrier with a `count` of 0 or 1 never blocks.
     pub const fn new(count: usize) -> Self {
         Self {
             count,
-            current: AtomicUsize::new(0),
+            arrived: AtomicUsize::new(0),
+            generation: AtomicUsize::new(0),
         }
     }
 
-    /// Wait for the barrier to be reached by every thread.
+    /// Wait for the barrier to be reached by every task.
     ///
-    /// A single task will get a [`BarrierWaitFuture`] that resolves to true.
-    /// This is the equivalent of the standard library method [`BarrierWaitResult::is_leader`](https://doc.rust-lang.org/std/sync/struct.BarrierWaitResult.html#method.is_leader)
-    pub fn wait(&self) -> BarrierWaitFuture<'_> {
-        let leader = {
-            let current = self.current.load(core::sync::atomic::Ordering::Acquire) + 1 % self.count;
-            self.current.store(current, Ordering::SeqCst);
-            current == 1
-        };
+    /// The returned future resolves once `count` tasks (including this one) are waiting on the
+    /// barrier. Exactly one of those tasks, the last to arrive, will get a [`BarrierWaitFuture`]
+    /// that resolves to true. This is the equivalent of the standard library method
+    /// [`BarrierWaitResult::is_leader`](https://doc.rust-lang.org/std/sync/struct.BarrierWaitResult.html#method.is_leader)
+    pub const fn wait(&self) -> BarrierWaitFuture<'_> {
         BarrierWaitFuture {
-            leader,
             barrier: self,
+            generation: None,
         }
     }
 }
+
 impl Debug for Barrier {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Barrier")
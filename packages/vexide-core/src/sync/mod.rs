@@ -8,6 +8,7 @@ mod lazy;
 mod mutex;
 mod once;
 mod rwlock;
+mod semaphore;
 
 pub use barrier::{Barrier, BarrierWaitFuture};
 pub use condvar::{Condvar, CondvarWaitFuture};
@@ -15,3 +16,4 @@ pub use lazy::LazyLock;
 pub use mutex::{Mutex, MutexGuard, MutexLockFuture, RawMutex};
 pub use once::{Once, OnceLock};
 pub use rwlock::{RwLock, RwLockReadFuture, RwLockReadGuard, RwLockWriteFuture, RwLockWriteGuard};
+pub use semaphore::{Semaphore, SemaphoreAcquireFuture, SemaphorePermit};
@@ -0,0 +1,121 @@
+use core::{
+    fmt::Debug,
+    mem::ManuallyDrop,
+    pin::Pin,
+    sync::atomic::{AtomicUsize, Ordering},
+    task::{Context, Poll},
+};
+
+use futures_core::Future;
+
+/// A future that resolves to a [`SemaphorePermit`] once one is available.
+/// This is created by [`Semaphore::acquire`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SemaphoreAcquireFuture<'a> {
+    semaphore: &'a Semaphore,
+}
+
+impl<'a> Future for SemaphoreAcquireFuture<'a> {
+    type Output = SemaphorePermit<'a>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Some(permit) = self.semaphore.try_acquire() {
+            Poll::Ready(permit)
+        } else {
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+/// Limits the number of tasks that can access a resource at the same time.
+///
+/// A semaphore starts with a fixed number of permits. Each task acquires a permit before using the
+/// resource and releases it when it's done by dropping the [`SemaphorePermit`]. Once every permit is
+/// taken, further tasks wait until one is released. A semaphore with a single permit behaves like a
+/// [`Mutex`](super::Mutex) that doesn't hold any data.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide::core::{fs, sync::Semaphore};
+///
+/// // Allow at most two tasks to write to the SD card at once.
+/// static SD_CARD: Semaphore = Semaphore::new(2);
+///
+/// async fn save_log(path: &str, contents: &[u8]) {
+///     let _permit = SD_CARD.acquire().await;
+///     fs::write(path, contents).ok();
+/// } // The permit is released here.
+/// ```
+pub struct Semaphore {
+    permits: AtomicUsize,
+}
+
+impl Semaphore {
+    /// Creates a new semaphore with the given number of permits.
+    pub const fn new(permits: usize) -> Self {
+        Self {
+            permits: AtomicUsize::new(permits),
+        }
+    }
+
+    /// Returns the number of permits that are currently available.
+    pub fn available_permits(&self) -> usize {
+        self.permits.load(Ordering::Acquire)
+    }
+
+    /// Waits until a permit is available, then acquires it.
+    ///
+    /// The permit is released when the returned [`SemaphorePermit`] is dropped.
+    pub const fn acquire(&self) -> SemaphoreAcquireFuture<'_> {
+        SemaphoreAcquireFuture { semaphore: self }
+    }
+
+    /// Attempts to acquire a permit without waiting, returning `None` if none are available.
+    pub fn try_acquire(&self) -> Option<SemaphorePermit<'_>> {
+        self.permits
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |permits| {
+                permits.checked_sub(1)
+            })
+            .ok()
+            .map(|_| SemaphorePermit { semaphore: self })
+    }
+
+    /// Adds `permits` new permits to the semaphore.
+    pub fn add_permits(&self, permits: usize) {
+        self.permits.fetch_add(permits, Ordering::AcqRel);
+    }
+}
+
+impl Debug for Semaphore {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Semaphore")
+            .field("permits", &self.available_permits())
+            .finish()
+    }
+}
+
+/// A permit acquired from a [`Semaphore`].
+///
+/// The permit is returned to the semaphore when this is dropped.
+#[derive(Debug)]
+#[must_use = "if unused the permit will immediately be released"]
+pub struct SemaphorePermit<'a> {
+    semaphore: &'a Semaphore,
+}
+
+impl SemaphorePermit<'_> {
+    /// Consumes the permit without returning it to the semaphore, permanently reducing the number
+    /// of available permits.
+    pub fn forget(self) {
+        _ = ManuallyDrop::new(self);
+    }
+}
+
+impl Drop for SemaphorePermit<'_> {
+    fn drop(&mut self) {
+        self.semaphore.add_permits(1);
+    }
+}
@@ -1,6 +1,9 @@
 //! Temporal quantification.
 //!
 //! This module provides an implementation of [`Instant`] built on the VEXos high-resolution timer.
+//!
+//! [`micros`] and [`delay_us`] are also provided for low-level code that needs microsecond timing
+//! without going through the async runtime.
 
 use core::{
     fmt,
@@ -172,3 +175,29 @@ impl fmt::Debug for Instant {
         self.0.fmt(f)
     }
 }
+
+/// Returns the number of microseconds since the user program started.
+///
+/// This reads the same clock as [`Instant::now`], but returns the raw value for low-level
+/// code that needs to do its own timing math.
+pub fn micros() -> u64 {
+    unsafe { vex_sdk::vexSystemHighResTimeGet() }
+}
+
+/// Busy-waits for at least the given number of microseconds.
+///
+/// This is intended for short, timing-critical sections of code, such as bit-banging a protocol
+/// over an ADI port.
+///
+/// # Blocking
+///
+/// This function **does not yield to the async executor**. No other tasks will run, and VEXos
+/// background processing (such as flushing serial output and updating devices) will stall until it
+/// returns. For anything longer than a few milliseconds, use an async sleep instead.
+pub fn delay_us(us: u32) {
+    let start = micros();
+
+    while micros() - start < us as u64 {
+        core::hint::spin_loop();
+    }
+}
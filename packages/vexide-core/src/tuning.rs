@@ -0,0 +1,191 @@
+//! Tuning parameters stored on the SD card.
+//!
+//! Changing a constant such as a PID gain normally means rebuilding and re-uploading the whole
+//! program. [`Tuning`] instead reads named parameters from a text file on the SD card, so they can
+//! be adjusted by editing the file and restarting the program. Every parameter is read with a
+//! compiled-in default, which is used if the file (or the parameter) is missing.
+//!
+//! # Format
+//!
+//! Tuning files use a simple INI-like format, with one `key = value` pair per line:
+//!
+//! ```text
+//! # Lines starting with `#` or `;` are comments.
+//! max_speed = 0.8
+//!
+//! [lift]
+//! kp = 1.25
+//! ki = 0.0
+//! kd = 0.1
+//! ```
+//!
+//! Pairs after a `[section]` header have the section name prepended to their key with a `.`, so
+//! the gains above are read as `lift.kp`, `lift.ki` and `lift.kd`. Values may be wrapped in double
+//! quotes, which are removed. Lines that can't be understood are logged and skipped.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use vexide::core::tuning::Tuning;
+//!
+//! let tuning = Tuning::load("tuning.ini");
+//!
+//! let kp = tuning.get("lift.kp", 1.0);
+//! let ki = tuning.get("lift.ki", 0.0);
+//! let kd = tuning.get("lift.kd", 0.1);
+//! ```
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+use core::{fmt::Write as _, str::FromStr};
+
+use no_std_io::io;
+
+use crate::fs;
+
+/// A set of named parameters loaded from a tuning file.
+///
+/// See the [module-level documentation](self) for the file format.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Tuning {
+    values: BTreeMap<String, String>,
+}
+
+impl Tuning {
+    /// Creates an empty set of parameters, in which every parameter has its default value.
+    pub const fn new() -> Self {
+        Self {
+            values: BTreeMap::new(),
+        }
+    }
+
+    /// Loads parameters from a file on the SD card.
+    ///
+    /// If the file can't be read (for example, if it doesn't exist or no SD card is inserted), a
+    /// warning is logged and an empty set of parameters is returned, so that every parameter falls
+    /// back to its default value.
+    pub fn load(path: &str) -> Self {
+        match fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents),
+            Err(error) => {
+                crate::io::warn!("Couldn't read tuning file {path:?}, using defaults: {error}");
+                Self::new()
+            }
+        }
+    }
+
+    /// Parses parameters from the contents of a tuning file.
+    ///
+    /// Lines that aren't comments, section headers or `key = value` pairs are logged and skipped.
+    /// If a key appears more than once, the last value is used.
+    pub fn parse(contents: &str) -> Self {
+        let mut tuning = Self::new();
+        let mut section = "";
+
+        for (number, line) in contents.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with(['#', ';']) {
+                continue;
+            }
+
+            if let Some(name) = line
+                .strip_prefix('[')
+                .and_then(|line| line.strip_suffix(']'))
+            {
+                section = name.trim();
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                crate::io::warn!(
+                    "Skipping malformed line {} in tuning file: {line:?}",
+                    number + 1
+                );
+                continue;
+            };
+
+            let key = key.trim();
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+                .unwrap_or(value);
+
+            let key = if section.is_empty() {
+                key.to_string()
+            } else {
+                alloc::format!("{section}.{key}")
+            };
+
+            tuning.values.insert(key, value.to_string());
+        }
+
+        tuning
+    }
+
+    /// Returns the value of a parameter, or `default` if it isn't set.
+    ///
+    /// The value is parsed with [`FromStr`], so any type that implements it (such as [`f64`],
+    /// [`i32`] and [`bool`]) can be read. If the value can't be parsed as `T`, a warning is logged
+    /// and `default` is returned.
+    pub fn get<T: FromStr>(&self, key: &str, default: T) -> T {
+        let Some(value) = self.values.get(key) else {
+            return default;
+        };
+
+        value.parse().unwrap_or_else(|_| {
+            crate::io::warn!(
+                "Tuning parameter {key:?} has an invalid value {value:?}, using default"
+            );
+            default
+        })
+    }
+
+    /// Returns the unparsed value of a parameter, or [`None`] if it isn't set.
+    pub fn get_raw(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    /// Returns `true` if a parameter is set.
+    pub fn contains(&self, key: &str) -> bool {
+        self.values.contains_key(key)
+    }
+
+    /// Returns an iterator over every parameter and its unparsed value, sorted by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Sets the value of a parameter.
+    ///
+    /// This only changes the parameters in memory. Use [`Tuning::save`] to write them back to the
+    /// SD card.
+    pub fn set(&mut self, key: &str, value: impl ToString) {
+        self.values.insert(key.to_string(), value.to_string());
+    }
+
+    /// Removes a parameter, so that it falls back to its default value.
+    pub fn remove(&mut self, key: &str) {
+        self.values.remove(key);
+    }
+
+    /// Writes every parameter to a file on the SD card, replacing its contents.
+    ///
+    /// Parameters are written without section headers, using their full keys (such as `lift.kp`),
+    /// so the file reads back as the same parameters. Any comments in the original file are lost.
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        let mut contents = String::new();
+
+        for (key, value) in &self.values {
+            // Writing to a `String` can't fail.
+            _ = writeln!(contents, "{key} = {value}");
+        }
+
+        fs::write(path, contents)
+    }
+}
@@ -2,6 +2,10 @@
 //!
 //! This module contains abstractions for interacting with WS2812B addressable smart LED
 //! strips over ADI ports.
+//!
+//! A single ADI port can drive a strip of up to [`AdiAddrLed::MAX_LENGTH`] diodes. The overall
+//! brightness of the strip can be scaled with [`AdiAddrLed::set_brightness`] without changing
+//! the colors written to it.
 
 use alloc::{vec, vec::Vec};
 
@@ -9,15 +13,18 @@ use snafu::Snafu;
 use vex_sdk::vexDeviceAdiAddrLedSet;
 
 use super::{AdiDevice, AdiDeviceType, AdiPort};
-#[cfg(feature = "smart_leds_trait")]
-use crate::color::Rgb;
-use crate::{color::IntoRgb, PortError};
+use crate::{
+    color::{IntoRgb, Rgb},
+    registry::{self, AdiRole},
+    PortError,
+};
 
 /// WS2812B Addressable LED Strip
 #[derive(Debug, Eq, PartialEq)]
 pub struct AdiAddrLed {
     port: AdiPort,
     buf: Vec<u32>,
+    brightness: u8,
 }
 
 impl AdiAddrLed {
@@ -25,30 +32,80 @@ impl AdiAddrLed {
     pub const MAX_LENGTH: usize = 64;
 
     /// Initialize an LED strip on an ADI port with a given number of diodes.
-    pub fn new<T, I>(port: AdiPort, length: usize) -> Result<Self, AddrLedError> {
+    ///
+    /// Returns [`AddrLedError::BufferTooLarge`] if `length` exceeds [`AdiAddrLed::MAX_LENGTH`].
+    pub fn new(port: AdiPort, length: usize) -> Result<Self, AddrLedError> {
         if length > Self::MAX_LENGTH {
             return Err(AddrLedError::BufferTooLarge);
         }
 
+        registry::register_adi(&port, AdiRole::AddrLed);
+
         Ok(Self {
             port,
             buf: vec![0; length],
+            brightness: u8::MAX,
         })
     }
 
     fn update(&mut self) {
-        unsafe {
-            vexDeviceAdiAddrLedSet(
-                self.port.device_handle(),
-                self.port.index(),
-                self.buf.as_mut_ptr(),
-                0,
-                self.buf.len() as u32,
-                0,
-            );
+        if self.brightness == u8::MAX {
+            unsafe {
+                vexDeviceAdiAddrLedSet(
+                    self.port.device_handle(),
+                    self.port.index(),
+                    self.buf.as_mut_ptr(),
+                    0,
+                    self.buf.len() as u32,
+                    0,
+                );
+            }
+        } else {
+            let brightness = self.brightness as u32;
+            let mut scaled = self
+                .buf
+                .iter()
+                .map(|&pixel| {
+                    let Rgb { r, g, b } = Rgb::from_raw(pixel);
+                    let scale = |channel: u8| (channel as u32 * brightness / 255) as u8;
+
+                    u32::from(Rgb::new(scale(r), scale(g), scale(b)))
+                })
+                .collect::<Vec<_>>();
+
+            unsafe {
+                vexDeviceAdiAddrLedSet(
+                    self.port.device_handle(),
+                    self.port.index(),
+                    scaled.as_mut_ptr(),
+                    0,
+                    scaled.len() as u32,
+                    0,
+                );
+            }
         }
     }
 
+    /// Returns the brightness that colors on the strip are scaled by, where `255` is full
+    /// brightness.
+    pub const fn brightness(&self) -> u8 {
+        self.brightness
+    }
+
+    /// Sets the brightness that colors on the strip are scaled by, where `255` is full brightness
+    /// and `0` turns every diode off.
+    ///
+    /// The strip is immediately updated with the new brightness. Colors set on the strip are kept
+    /// at their original values, so lowering and then raising the brightness is lossless.
+    pub fn set_brightness(&mut self, brightness: u8) -> Result<(), AddrLedError> {
+        self.port.validate_expander()?;
+
+        self.brightness = brightness;
+        self.update();
+
+        Ok(())
+    }
+
     /// Set the entire led strip to one color.
     pub fn set_all(&mut self, color: impl IntoRgb) -> Result<(), AddrLedError> {
         _ = self.set_buffer(vec![u32::from(color.into_rgb()); self.buf.len()])?;
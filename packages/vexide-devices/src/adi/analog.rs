@@ -11,6 +11,7 @@
 use vex_sdk::vexDeviceAdiValueGet;
 
 use super::{AdiDevice, AdiDeviceType, AdiPort, PortError};
+use crate::sensor::Sensor;
 
 /// The maximum 12-bit analog value returned by the internal
 /// analog-to-digital converters on the brain.
@@ -62,6 +63,16 @@ impl AdiAnalogIn {
     }
 }
 
+impl Sensor for AdiAnalogIn {
+    type Reading = u16;
+    type Error = PortError;
+
+    /// Reads the [analog value](AdiAnalogIn::value) on the port.
+    fn read(&self) -> Result<Self::Reading, Self::Error> {
+        self.value()
+    }
+}
+
 impl AdiDevice for AdiAnalogIn {
     type PortNumberOutput = u8;
 
@@ -1,8 +1,13 @@
 //! Digital input and output ADI devices
 
+use core::time::Duration;
+
 use vex_sdk::{vexDeviceAdiValueGet, vexDeviceAdiValueSet};
+use vexide_async::debounce::Debouncer;
+use vexide_core::sync::Mutex;
 
 use super::{AdiDevice, AdiDeviceType, AdiPort, PortError};
+use crate::sensor::Sensor;
 
 /// Represents the logic level of a digital pin.
 ///
@@ -52,28 +57,85 @@ impl core::ops::Not for LogicLevel {
 }
 
 /// Generic digital input ADI device.
-#[derive(Debug, Eq, PartialEq)]
-/// Generic digital input ADI device.
+///
+/// # Debouncing
+///
+/// Mechanical switches such as limit switches and bumpers "bounce" when pressed or released,
+/// rapidly flickering between high and low for a few milliseconds before settling. This can cause
+/// a single press to be read as several. [`AdiDigitalIn::set_debounce`] filters this out by only
+/// reporting a new level once the input has held it for the given amount of time.
+#[derive(Debug)]
 pub struct AdiDigitalIn {
     port: AdiPort,
+    debounce: Duration,
+    /// Whether the level is high, filtered by the debounce window.
+    debouncer: Mutex<Debouncer>,
+}
+
+// The debounce state is transient, so it isn't compared.
+impl PartialEq for AdiDigitalIn {
+    fn eq(&self, other: &Self) -> bool {
+        self.port == other.port && self.debounce == other.debounce
+    }
 }
 
+impl Eq for AdiDigitalIn {}
+
 impl AdiDigitalIn {
     /// Create a digital input from an ADI port.
     pub fn new(port: AdiPort) -> Self {
         port.configure(AdiDeviceType::DigitalIn);
 
-        Self { port }
+        Self {
+            port,
+            debounce: Duration::ZERO,
+            debouncer: Mutex::new(Debouncer::new(Duration::ZERO)),
+        }
+    }
+
+    /// Sets how long the input must hold a new level before it is reported.
+    ///
+    /// A window of 10-20 milliseconds is usually enough for mechanical switches. Changes in level
+    /// are delayed by the length of the window, so it should be kept as short as possible. The
+    /// input is only sampled when it is read, so it should be read regularly (for example, every
+    /// iteration of a control loop) for the window to be measured accurately.
+    ///
+    /// Debouncing is disabled by default. Setting the window to [`Duration::ZERO`] disables it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// let mut limit_switch = AdiDigitalIn::new(peripherals.adi_a);
+    /// limit_switch.set_debounce(Duration::from_millis(15));
+    /// ```
+    pub fn set_debounce(&mut self, window: Duration) {
+        self.debounce = window;
+        self.debouncer.get_mut().set_window(window);
+    }
+
+    /// Returns how long the input must hold a new level before it is reported.
+    ///
+    /// See [`AdiDigitalIn::set_debounce`] for more information.
+    pub const fn debounce(&self) -> Duration {
+        self.debounce
     }
 
     /// Gets the current logic level of a digital input pin.
+    ///
+    /// If [debouncing](AdiDigitalIn::set_debounce) is enabled, this returns the last level that was
+    /// held for the entire debounce window.
     pub fn level(&self) -> Result<LogicLevel, PortError> {
         self.port.validate_expander()?;
         self.port.configure(self.device_type());
 
-        let value =
+        let mut value =
             unsafe { vexDeviceAdiValueGet(self.port.device_handle(), self.port.index()) } != 0;
 
+        // The lock is only ever held for the rest of this call, so it is always available here.
+        if let Some(mut debouncer) = self.debouncer.try_lock() {
+            value = debouncer.update(value);
+        }
+
         Ok(match value {
             true => LogicLevel::High,
             false => LogicLevel::Low,
@@ -87,7 +149,17 @@ impl AdiDigitalIn {
 
     /// Returns `true` if the digital input's logic level level is [`LogicLevel::Low`].
     pub fn is_low(&self) -> Result<bool, PortError> {
-        Ok(self.level()?.is_high())
+        Ok(self.level()?.is_low())
+    }
+}
+
+impl Sensor for AdiDigitalIn {
+    type Reading = bool;
+    type Error = PortError;
+
+    /// Reads whether the digital signal on the port [is high](AdiDigitalIn::is_high).
+    fn read(&self) -> Result<Self::Reading, Self::Error> {
+        self.is_high()
     }
 }
 
@@ -4,13 +4,18 @@ use snafu::Snafu;
 use vex_sdk::{vexDeviceAdiValueGet, vexDeviceAdiValueSet};
 
 use super::{AdiDevice, AdiDeviceType, AdiPort};
-use crate::{position::Position, PortError};
+use crate::{
+    position::{Direction, Position},
+    sensor::Sensor,
+    PortError,
+};
 
 /// VEX V5 Optical Shaft Encoder
 #[derive(Debug, Eq, PartialEq)]
 pub struct AdiEncoder {
     top_port: AdiPort,
     bottom_port: AdiPort,
+    direction: Direction,
 }
 
 impl AdiEncoder {
@@ -18,7 +23,10 @@ impl AdiEncoder {
     pub const TICKS_PER_REVOLUTION: u32 = 360;
 
     /// Create a new encoder sensor from a top and bottom [`AdiPort`].
-    pub fn new(ports: (AdiPort, AdiPort)) -> Result<Self, EncoderError> {
+    ///
+    /// If `direction` is [`Direction::Reverse`], positions are negated, so that the encoder counts
+    /// up when turned the other way.
+    pub fn new(ports: (AdiPort, AdiPort), direction: Direction) -> Result<Self, EncoderError> {
         let top_port = ports.0;
         let bottom_port = ports.1;
 
@@ -26,8 +34,8 @@ impl AdiEncoder {
         if top_port.expander_index() != bottom_port.expander_index() {
             // Top and bottom must be plugged into the same ADI expander.
             return Err(EncoderError::ExpanderPortMismatch);
-        } else if top_port.index() % 2 == 0 {
-            // Top must be on an odd indexed port (A, C, E, G).
+        } else if top_port.number() % 2 == 0 {
+            // Top must be on an odd numbered port (A, C, E, G).
             return Err(EncoderError::BadTopPort);
         } else if bottom_port.index() != (top_port.index() + 1) {
             // Bottom must be directly next to top on the higher port index.
@@ -39,9 +47,25 @@ impl AdiEncoder {
         Ok(Self {
             top_port,
             bottom_port,
+            direction,
         })
     }
 
+    /// Returns the direction that the encoder measures rotation in.
+    pub const fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Sets the direction that the encoder measures rotation in.
+    ///
+    /// The encoder's current position is kept, and only future movement is measured in the new
+    /// direction.
+    pub fn set_direction(&mut self, direction: Direction) -> Result<(), EncoderError> {
+        let position = self.position()?;
+        self.direction = direction;
+        self.set_position(position)
+    }
+
     /// Get the distance reading of the encoder sensor in centimeters.
     ///
     /// Round and/or fluffy objects can cause inaccurate values to be returned.
@@ -49,9 +73,14 @@ impl AdiEncoder {
         self.top_port.validate_expander()?;
         self.top_port.configure(self.device_type());
 
+        let ticks =
+            unsafe { vexDeviceAdiValueGet(self.top_port.device_handle(), self.top_port.index()) }
+                as i64;
+
         Ok(Position::from_ticks(
-            unsafe {
-                vexDeviceAdiValueGet(self.top_port.device_handle(), self.top_port.index()) as i64
+            match self.direction {
+                Direction::Forward => ticks,
+                Direction::Reverse => -ticks,
             },
             360,
         ))
@@ -63,11 +92,16 @@ impl AdiEncoder {
     pub fn set_position(&self, position: Position) -> Result<(), EncoderError> {
         self.top_port.validate_expander()?;
 
+        let ticks = position.as_ticks(360) as i32;
+
         unsafe {
             vexDeviceAdiValueSet(
                 self.top_port.device_handle(),
                 self.top_port.index(),
-                position.as_ticks(360) as i32,
+                match self.direction {
+                    Direction::Forward => ticks,
+                    Direction::Reverse => -ticks,
+                },
             )
         }
 
@@ -83,6 +117,16 @@ impl AdiEncoder {
     }
 }
 
+impl Sensor for AdiEncoder {
+    type Reading = Position;
+    type Error = EncoderError;
+
+    /// Reads the encoder's [position](AdiEncoder::position).
+    fn read(&self) -> Result<Self::Reading, Self::Error> {
+        self.position()
+    }
+}
+
 impl AdiDevice for AdiEncoder {
     type PortNumberOutput = (u8, u8);
 
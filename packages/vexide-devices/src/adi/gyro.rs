@@ -0,0 +1,216 @@
+//! ADI Gyroscope
+//!
+//! # Overview
+//!
+//! The Cortex-era yaw-rate gyroscope is an analog sensor that measures the robot's rotation
+//! about a single axis. The brain integrates the gyro's rate output internally, so readings
+//! from this device are the total angle the gyro has turned since it was calibrated.
+//!
+//! # Calibration
+//!
+//! The gyro calibrates itself when its port is first configured, which takes roughly 1.3 seconds.
+//! The sensor must be kept completely still during this time, otherwise its readings will drift.
+//! Calibration can be restarted with [`AdiGyro::calibrate`], which additionally waits for it to finish.
+//! Since the gyro's angle is only meaningful relative to some starting point, [`AdiGyro::new_zeroed`]
+//! can be used to reset the angle to zero as soon as the gyro is created.
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use vex_sdk::{vexDeviceAdiValueGet, vexDeviceAdiValueSet};
+use vexide_core::time::Instant;
+
+use super::{AdiAverageFuture, AdiDevice, AdiDeviceType, AdiPort};
+use crate::{calibrate::Calibrate, position::Position, sensor::Sensor, PortError};
+
+/// Cortex-era yaw-rate gyroscope.
+#[derive(Debug, PartialEq)]
+pub struct AdiGyro {
+    port: AdiPort,
+    multiplier: f64,
+}
+
+impl AdiGyro {
+    /// The approximate amount of time it takes for the gyro to calibrate.
+    pub const CALIBRATION_TIME: Duration = Duration::from_millis(1300);
+
+    /// Create a new gyro from an [`AdiPort`].
+    ///
+    /// `multiplier` scales every reading from the gyro, and can be used to correct a gyro that consistently
+    /// over or under-reports its rotation. Use `1.0` for no correction.
+    pub fn new(port: AdiPort, multiplier: f64) -> Self {
+        port.configure(AdiDeviceType::Gyro);
+
+        Self { port, multiplier }
+    }
+
+    /// Create a new gyro from an [`AdiPort`], resetting its angle to zero.
+    ///
+    /// This is equivalent to calling [`AdiGyro::new`] followed by [`AdiGyro::reset`]. Note that the
+    /// gyro still needs roughly 1.3 seconds to finish calibrating after being created, and its readings
+    /// won't be accurate until it has settled.
+    pub fn new_zeroed(port: AdiPort, multiplier: f64) -> Result<Self, PortError> {
+        let mut gyro = Self::new(port, multiplier);
+        gyro.reset()?;
+
+        Ok(gyro)
+    }
+
+    /// Returns the multiplier applied to the gyro's readings.
+    pub const fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
+    /// Sets the multiplier applied to the gyro's readings.
+    pub fn set_multiplier(&mut self, multiplier: f64) {
+        self.multiplier = multiplier;
+    }
+
+    /// Ensures that the port is configured as a gyro.
+    ///
+    /// Unlike other ADI devices, this only reconfigures the port if it isn't already a gyro, since
+    /// configuring the port restarts the gyro's calibration.
+    fn validate(&self) -> Result<(), PortError> {
+        if self.port.configured_type()? != AdiDeviceType::Gyro {
+            self.port.configure(AdiDeviceType::Gyro);
+        }
+
+        Ok(())
+    }
+
+    /// Restarts the gyro's calibration, returning a future that resolves once the gyro has had
+    /// [`AdiGyro::CALIBRATION_TIME`] to calibrate.
+    ///
+    /// The gyro must be kept completely still until the future resolves. The gyro's angle is
+    /// reset to zero by calibration.
+    pub fn calibrate(&mut self) -> AdiGyroCalibrateFuture<'_> {
+        AdiGyroCalibrateFuture {
+            gyro: self,
+            started: None,
+        }
+    }
+
+    /// Resets the gyro's angle to zero.
+    ///
+    /// This doesn't restart the gyro's calibration.
+    pub fn reset(&mut self) -> Result<(), PortError> {
+        self.validate()?;
+
+        unsafe { vexDeviceAdiValueSet(self.port.device_handle(), self.port.index(), 0) }
+
+        Ok(())
+    }
+
+    /// Returns the raw angle reported by the brain in tenths of a degree, without the multiplier applied.
+    pub fn raw_value(&self) -> Result<i32, PortError> {
+        self.validate()?;
+
+        Ok(unsafe { vexDeviceAdiValueGet(self.port.device_handle(), self.port.index()) })
+    }
+
+    /// Returns the angle the gyro has turned in degrees, with the multiplier applied.
+    ///
+    /// Unlike [`AdiGyro::raw_value`], this has already been converted from tenths of a degree.
+    pub fn value(&self) -> Result<f64, PortError> {
+        Ok(self.raw_value()? as f64 / 10.0 * self.multiplier)
+    }
+
+    /// Returns the angle the gyro has turned in radians, with the multiplier applied.
+    pub fn radians(&self) -> Result<f64, PortError> {
+        Ok(self.value()?.to_radians())
+    }
+
+    /// Returns the angle the gyro has turned as a [`Position`], with the multiplier applied.
+    pub fn position(&self) -> Result<Position, PortError> {
+        Ok(Position::from_degrees(self.value()?))
+    }
+
+    /// Returns a future that averages several readings from the gyro to reduce noise.
+    ///
+    /// One sample is taken every [`ADI_UPDATE_INTERVAL`](super::ADI_UPDATE_INTERVAL), since that is
+    /// how often the brain receives new data from ADI devices. The future sleeps between samples
+    /// rather than blocking. If `samples` is zero, a single sample is taken.
+    pub const fn value_averaged(&self, samples: u8) -> AdiGyroAverageFuture<'_> {
+        AdiAverageFuture::new(self, Self::value, samples)
+    }
+}
+
+impl Sensor for AdiGyro {
+    type Reading = Position;
+    type Error = PortError;
+
+    /// Reads the gyro's [position](AdiGyro::position).
+    fn read(&self) -> Result<Self::Reading, Self::Error> {
+        self.position()
+    }
+}
+
+impl AdiDevice for AdiGyro {
+    type PortNumberOutput = u8;
+
+    fn port_number(&self) -> Self::PortNumberOutput {
+        self.port.number()
+    }
+
+    fn expander_port_number(&self) -> Option<u8> {
+        self.port.expander_number()
+    }
+
+    fn device_type(&self) -> AdiDeviceType {
+        AdiDeviceType::Gyro
+    }
+}
+
+impl Calibrate for AdiGyro {
+    type Error = PortError;
+    type Future<'a> = AdiGyroCalibrateFuture<'a>;
+
+    fn calibrate(&mut self) -> Self::Future<'_> {
+        AdiGyro::calibrate(self)
+    }
+}
+
+/// A future that calibrates an [`AdiGyro`].
+///
+/// See [`AdiGyro::calibrate`] for more information.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct AdiGyroCalibrateFuture<'a> {
+    gyro: &'a mut AdiGyro,
+    started: Option<Instant>,
+}
+
+impl Future for AdiGyroCalibrateFuture<'_> {
+    type Output = Result<(), PortError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let started = match self.started {
+            Some(started) => started,
+            None => {
+                if let Err(err) = self.gyro.port.validate_expander() {
+                    return Poll::Ready(Err(err));
+                }
+
+                // Configuring the port as a gyro restarts its calibration.
+                self.gyro.port.configure(AdiDeviceType::Gyro);
+                *self.started.insert(Instant::now())
+            }
+        };
+
+        if started.elapsed() >= AdiGyro::CALIBRATION_TIME {
+            return Poll::Ready(Ok(()));
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+/// A future that averages several readings from an [`AdiGyro`].
+///
+/// See [`AdiGyro::value_averaged`] for more information.
+pub type AdiGyroAverageFuture<'a> = AdiAverageFuture<'a, AdiGyro>;
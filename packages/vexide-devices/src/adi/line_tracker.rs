@@ -22,23 +22,111 @@
 //! For best results when using the Line Tracking Sensors, it is best to mount the sensors
 //! between 1/8 and 1/4 of an inch away from the surface it is measuring. It is also important
 //! to keep lighting in the room consistent, so sensors' readings remain accurate.
+//!
+//! # Line Detection
+//!
+//! [`AdiLineTracker::is_over_line`] compares the sensor's raw reading with a threshold to decide
+//! whether the sensor is over a line. Since lighting conditions vary, the threshold should be
+//! calibrated by taking readings over both the line and the background surface:
+//!
+//! ```no_run
+//! let mut tracker = AdiLineTracker::new(peripherals.adi_a);
+//!
+//! // With the sensor over the background...
+//! let background = tracker.average_raw_reflectivity(10).await?;
+//! // ...and then with the sensor over the line.
+//! let line = tracker.average_raw_reflectivity(10).await?;
+//!
+//! tracker.calibrate(line, background);
+//! ```
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
 
 use vex_sdk::vexDeviceAdiValueGet;
 
-use super::{analog, AdiDevice, AdiDeviceType, AdiPort, PortError};
+use super::{
+    analog, AdiAverageFuture, AdiDevice, AdiDeviceType, AdiPort, PortError, ADI_UPDATE_INTERVAL,
+};
 
 /// ADI Line Tracker
 #[derive(Debug, Eq, PartialEq)]
 pub struct AdiLineTracker {
     port: AdiPort,
+    threshold: u16,
+    dark_line: bool,
 }
 
 impl AdiLineTracker {
+    /// The default threshold used by [`AdiLineTracker::is_over_line`] before calibration.
+    pub const DEFAULT_THRESHOLD: u16 = analog::ADC_MAX_VALUE / 2;
+
     /// Create a line tracker from an ADI port.
+    ///
+    /// By default, the tracker detects a dark line on a light background using
+    /// [`AdiLineTracker::DEFAULT_THRESHOLD`].
     pub fn new(port: AdiPort) -> Self {
         port.configure(AdiDeviceType::LineTracker);
 
-        Self { port }
+        Self {
+            port,
+            threshold: Self::DEFAULT_THRESHOLD,
+            dark_line: true,
+        }
+    }
+
+    /// Returns the raw reading at which the sensor is considered to be over a line.
+    pub const fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Sets the raw reading at which the sensor is considered to be over a line.
+    ///
+    /// If `dark_line` is `true`, readings at or above the threshold (less reflective surfaces) are
+    /// considered to be a line. Otherwise, readings at or below the threshold are.
+    pub fn set_threshold(&mut self, threshold: u16, dark_line: bool) {
+        self.threshold = threshold.min(analog::ADC_MAX_VALUE);
+        self.dark_line = dark_line;
+    }
+
+    /// Calibrates the line detection threshold from raw readings taken over the line and over
+    /// the background surface.
+    ///
+    /// The threshold is set halfway between the two readings, and whether the line is darker or
+    /// lighter than its background is determined automatically. See
+    /// [`AdiLineTracker::average_raw_reflectivity`] for taking these readings.
+    pub fn calibrate(&mut self, line: u16, background: u16) {
+        self.set_threshold(
+            ((line as u32 + background as u32) / 2) as u16,
+            line > background,
+        );
+    }
+
+    /// Returns `true` if the sensor is currently over a line.
+    ///
+    /// See [`AdiLineTracker::calibrate`] and [`AdiLineTracker::set_threshold`] to configure how lines
+    /// are detected.
+    pub fn is_over_line(&self) -> Result<bool, PortError> {
+        let raw = self.raw_reflectivity()?;
+
+        Ok(if self.dark_line {
+            raw >= self.threshold
+        } else {
+            raw <= self.threshold
+        })
+    }
+
+    /// Returns a future that averages several raw readings from the sensor.
+    ///
+    /// One sample is taken every [`ADI_UPDATE_INTERVAL`], and the future sleeps between samples.
+    /// If `samples` is zero, a single sample is taken.
+    pub const fn average_raw_reflectivity(&self, samples: u8) -> LineTrackerAverageFuture<'_> {
+        LineTrackerAverageFuture {
+            average: AdiAverageFuture::new(self, Self::raw_reflectivity_f64, samples),
+        }
     }
 
     /// Get the reflectivity factor measured by the sensor. Higher numbers mean
@@ -64,6 +152,11 @@ impl AdiLineTracker {
 
         Ok(unsafe { vexDeviceAdiValueGet(self.port.device_handle(), self.port.index()) } as u16)
     }
+
+    /// Reads [`AdiLineTracker::raw_reflectivity`] as an `f64` so that it can be averaged.
+    fn raw_reflectivity_f64(&self) -> Result<f64, PortError> {
+        Ok(self.raw_reflectivity()?.into())
+    }
 }
 
 impl AdiDevice for AdiLineTracker {
@@ -81,3 +174,22 @@ impl AdiDevice for AdiLineTracker {
         AdiDeviceType::LineTracker
     }
 }
+
+/// A future that averages several raw readings from an [`AdiLineTracker`].
+///
+/// See [`AdiLineTracker::average_raw_reflectivity`] for more information.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct LineTrackerAverageFuture<'a> {
+    average: AdiAverageFuture<'a, AdiLineTracker>,
+}
+
+impl Future for LineTrackerAverageFuture<'_> {
+    type Output = Result<u16, PortError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.average)
+            .poll(cx)
+            .map(|average| average.map(|average| average as u16))
+    }
+}
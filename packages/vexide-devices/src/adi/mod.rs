@@ -1,6 +1,11 @@
 //! ADI (Triport) devices on the Vex V5.
 
-use core::time::Duration;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
 
 use crate::PortError;
 
@@ -9,6 +14,7 @@ pub mod addrled;
 pub mod analog;
 pub mod digital;
 pub mod encoder;
+pub mod gyro;
 pub mod light_sensor;
 pub mod line_tracker;
 pub mod motor;
@@ -21,6 +27,7 @@ pub use accelerometer::{AdiAccelerometer, Sensitivity};
 pub use analog::AdiAnalogIn;
 pub use digital::{AdiDigitalIn, AdiDigitalOut};
 pub use encoder::AdiEncoder;
+pub use gyro::AdiGyro;
 pub use light_sensor::AdiLightSensor;
 pub use line_tracker::AdiLineTracker;
 pub use motor::AdiMotor;
@@ -32,8 +39,12 @@ use vex_sdk::{
     vexDeviceAdiPortConfigGet, vexDeviceAdiPortConfigSet, vexDeviceGetByIndex,
     V5_AdiPortConfiguration, V5_DeviceT,
 };
+use vexide_async::time::{sleep, Sleep};
 
-use crate::smart::{validate_port, SmartDeviceType};
+use crate::{
+    registry,
+    smart::{validate_port, SmartDeviceType},
+};
 
 /// Update rate for all ADI devices and ports.
 pub const ADI_UPDATE_INTERVAL: Duration = Duration::from_millis(10);
@@ -53,10 +64,18 @@ pub struct AdiPort {
 }
 
 impl AdiPort {
+    /// The smart port number that VEXos uses internally for the brain's onboard ADI ports.
+    ///
+    /// The brain's built-in ADI ports behave like an [`AdiExpander`](super::smart::AdiExpander)
+    /// plugged into this port, which is past the 21 physical smart ports.
     pub(crate) const INTERNAL_ADI_PORT_NUMBER: u8 = 22;
 
     /// Create a new port.
     ///
+    /// If `expander_number` is `None`, the port refers to one of the brain's onboard ADI ports.
+    /// Otherwise, it refers to a port on the [`AdiExpander`](super::smart::AdiExpander) plugged
+    /// into that smart port.
+    ///
     /// # Safety
     ///
     /// Creating new `AdiPort`s is inherently unsafe due to the possibility of constructing
@@ -69,6 +88,17 @@ impl AdiPort {
         }
     }
 
+    /// Create a new port on the brain's onboard ADI ports.
+    ///
+    /// This is equivalent to calling [`AdiPort::new`] without an expander.
+    ///
+    /// # Safety
+    ///
+    /// See [`AdiPort::new`].
+    pub const unsafe fn new_onboard(number: u8) -> Self {
+        unsafe { Self::new(number, None) }
+    }
+
     /// Get the number of the port.
     ///
     /// Ports are numbered starting from 1.
@@ -82,15 +112,25 @@ impl AdiPort {
         self.expander_number
     }
 
+    /// Returns `true` if this is one of the brain's onboard ADI ports rather than a port on an
+    /// [`AdiExpander`](super::smart::AdiExpander).
+    pub const fn is_onboard(&self) -> bool {
+        self.expander_number.is_none()
+    }
+
     pub(crate) const fn index(&self) -> u32 {
         (self.number - 1) as u32
     }
 
-    pub(crate) fn expander_index(&self) -> u32 {
-        ((self
-            .expander_number
-            .unwrap_or(Self::INTERNAL_ADI_PORT_NUMBER))
-            - 1) as u32
+    /// Returns the smart port index of the device that owns this port: either the expander it's
+    /// plugged into, or the brain's internal ADI device for onboard ports.
+    pub(crate) const fn expander_index(&self) -> u32 {
+        let expander_number = match self.expander_number {
+            Some(number) => number,
+            None => Self::INTERNAL_ADI_PORT_NUMBER,
+        };
+
+        (expander_number - 1) as u32
     }
 
     pub(crate) fn device_handle(&self) -> V5_DeviceT {
@@ -102,7 +142,12 @@ impl AdiPort {
     }
 
     /// Configures the ADI port to a specific type if it wasn't already configured.
+    ///
+    /// The port is also recorded in the device registry, so that it can be neutralized and
+    /// released when the program exits.
     pub(crate) fn configure(&self, config: AdiDeviceType) {
+        registry::register_adi(self, config.into());
+
         unsafe {
             vexDeviceAdiPortConfigSet(self.device_handle(), self.index(), config.into());
         }
@@ -175,6 +220,66 @@ pub trait AdiDevice {
     fn device_type(&self) -> AdiDeviceType;
 }
 
+/// A future that averages several readings from an ADI device.
+///
+/// One sample is taken every [`ADI_UPDATE_INTERVAL`], since that is how often the brain receives
+/// new data from ADI devices, and the future sleeps between samples. This is created by
+/// [`AdiGyro::value_averaged`] and [`AdiLineTracker::average_raw_reflectivity`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct AdiAverageFuture<'a, D> {
+    device: &'a D,
+    read: fn(&D) -> Result<f64, PortError>,
+    samples: u8,
+    taken: u8,
+    sum: f64,
+    sleep: Option<Sleep>,
+}
+
+impl<'a, D> AdiAverageFuture<'a, D> {
+    /// Creates a future that averages `samples` readings from `device`, taking at least one.
+    pub(crate) const fn new(
+        device: &'a D,
+        read: fn(&D) -> Result<f64, PortError>,
+        samples: u8,
+    ) -> Self {
+        Self {
+            device,
+            read,
+            samples: if samples == 0 { 1 } else { samples },
+            taken: 0,
+            sum: 0.0,
+            sleep: None,
+        }
+    }
+}
+
+impl<D> Future for AdiAverageFuture<'_, D> {
+    type Output = Result<f64, PortError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            if let Some(sleep) = &mut self.sleep {
+                if Pin::new(sleep).poll(cx).is_pending() {
+                    return Poll::Pending;
+                }
+            }
+
+            match (self.read)(self.device) {
+                Ok(value) => self.sum += value,
+                Err(err) => return Poll::Ready(Err(err)),
+            }
+
+            self.taken += 1;
+            if self.taken >= self.samples {
+                return Poll::Ready(Ok(self.sum / self.taken as f64));
+            }
+
+            self.sleep = Some(sleep(ADI_UPDATE_INTERVAL));
+        }
+    }
+}
+
 /// Represents a possible type of device that can be registered on a [`AdiPort`].
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum AdiDeviceType {
@@ -3,7 +3,7 @@
 use vex_sdk::vexDeviceAdiValueGet;
 
 use super::{analog, AdiDevice, AdiDeviceType, AdiPort};
-use crate::PortError;
+use crate::{sensor::Sensor, PortError};
 
 /// Analog potentiometer ADI device.
 #[derive(Debug, Eq, PartialEq)]
@@ -81,6 +81,16 @@ impl PotentiometerType {
     }
 }
 
+impl Sensor for AdiPotentiometer {
+    type Reading = f64;
+    type Error = PortError;
+
+    /// Reads the potentiometer's [angle](AdiPotentiometer::angle) in degrees.
+    fn read(&self) -> Result<Self::Reading, Self::Error> {
+        self.angle()
+    }
+}
+
 impl AdiDevice for AdiPotentiometer {
     type PortNumberOutput = u8;
 
@@ -1,4 +1,10 @@
 //! ADI Pulse-width modulation (PWM).
+//!
+//! # Analog Output
+//!
+//! ADI ports have no digital-to-analog converter, so they cannot output a true analog voltage. The
+//! SDK's "analog out" port configuration is actually this PWM output, which can be passed through an
+//! external RC low-pass filter if a steady voltage is needed for custom electronics.
 
 use vex_sdk::vexDeviceAdiValueSet;
 
@@ -9,7 +9,8 @@ use crate::PortError;
 /// ADI Range Finders.
 ///
 /// Requires two ports - one for pinging, and one for listening for the response.
-/// This output port ("output") must be indexed directly below the input ("input") port.
+/// The output port must be on an odd numbered port (A, C, E, G), and the input port must be the port
+/// directly after it.
 #[derive(Debug, Eq, PartialEq)]
 pub struct AdiRangeFinder {
     output_port: AdiPort,
@@ -26,10 +27,10 @@ impl AdiRangeFinder {
         if output_port.expander_index() != input_port.expander_index() {
             // Output and input must be plugged into the same ADI expander.
             return Err(RangeFinderError::ExpanderPortMismatch);
-        } else if output_port.index() % 2 == 0 {
-            // Output must be on an odd indexed port (A, C, E, G).
+        } else if output_port.number() % 2 == 0 {
+            // Output must be on an odd numbered port (A, C, E, G).
             return Err(RangeFinderError::BadOutputPort);
-        } else if input_port.index() != (output_port.index() - 1) {
+        } else if input_port.index() != (output_port.index() + 1) {
             // Input must be directly next to output on the higher port index.
             return Err(RangeFinderError::BadInputPort);
         }
@@ -83,7 +84,7 @@ pub enum RangeFinderError {
     /// The port number of the output wire must be odd (A, C, E, G).
     BadOutputPort,
 
-    /// The input  wire must be plugged in directly above the output wire.
+    /// The input wire must be plugged in directly above the output wire.
     BadInputPort,
 
     /// The specified output and input ports belong to different ADI expanders.
@@ -1,8 +1,16 @@
 //! Utilities for getting information about the robot's battery.
 
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
 use vex_sdk::{
     vexBatteryCapacityGet, vexBatteryCurrentGet, vexBatteryTemperatureGet, vexBatteryVoltageGet,
 };
+use vexide_core::time::Instant;
 
 /// Returns the robot's current battery capacity from [0.0, 1.0].
 ///
@@ -26,3 +34,63 @@ pub fn current() -> f64 {
 pub fn voltage() -> f64 {
     (unsafe { vexBatteryVoltageGet() } as f64) / 1000.0
 }
+
+/// Returns a future that resolves once the battery voltage has stayed below `threshold` volts for
+/// at least `debounce`.
+///
+/// Heavy loads such as pushing against another robot can cause the battery voltage to sag for a
+/// brief moment, so the voltage must remain below the threshold for the entire `debounce` duration
+/// before the future resolves. The future resolves with the battery voltage at that time.
+///
+/// # Examples
+///
+/// ```no_run
+/// use core::time::Duration;
+///
+/// // Wait for the voltage to stay under 11 volts for a quarter of a second.
+/// let voltage = battery::wait_for_brownout(11.0, Duration::from_millis(250)).await;
+/// println!("Brownout detected at {voltage}V, reducing current limits.");
+///
+/// for motor in &mut motors {
+///     motor.set_current_limit(1.5)?;
+/// }
+/// ```
+pub const fn wait_for_brownout(threshold: f64, debounce: Duration) -> BrownoutFuture {
+    BrownoutFuture {
+        threshold,
+        debounce,
+        below_since: None,
+    }
+}
+
+/// A future that resolves once the battery voltage drops below a threshold.
+///
+/// See [`wait_for_brownout`] for more information.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct BrownoutFuture {
+    threshold: f64,
+    debounce: Duration,
+    below_since: Option<Instant>,
+}
+
+impl Future for BrownoutFuture {
+    type Output = f64;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let voltage = voltage();
+
+        if voltage < self.threshold {
+            let below_since = *self.below_since.get_or_insert_with(Instant::now);
+
+            if below_since.elapsed() >= self.debounce {
+                return Poll::Ready(voltage);
+            }
+        } else {
+            self.below_since = None;
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
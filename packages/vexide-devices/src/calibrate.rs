@@ -0,0 +1,46 @@
+//! Device calibration.
+//!
+//! Some devices need to calibrate before their readings are accurate, which can take a few seconds.
+//! The [`Calibrate`] trait provides a common interface over these devices, so that several of them
+//! can be calibrated at the same time during startup.
+//!
+//! The following devices implement [`Calibrate`]:
+//!
+//! - [`InertialSensor`](crate::smart::imu::InertialSensor)
+//! - [`AdiGyro`](crate::adi::gyro::AdiGyro)
+//!
+//! # Example
+//!
+//! ```no_run
+//! use futures::join;
+//!
+//! let mut imu = InertialSensor::new(peripherals.port_1);
+//! let mut gyro = AdiGyro::new(peripherals.adi_a, 1.0);
+//!
+//! // Calibrate both devices at once rather than one after the other.
+//! let (imu_result, gyro_result) = join!(
+//!     Calibrate::calibrate(&mut imu),
+//!     Calibrate::calibrate(&mut gyro),
+//! );
+//! ```
+
+use core::future::Future;
+
+/// A device that needs to be calibrated before its readings are accurate.
+///
+/// See the [module-level documentation](self) for more information.
+pub trait Calibrate {
+    /// The error that can occur while calibrating the device.
+    type Error;
+
+    /// The future returned by [`Calibrate::calibrate`].
+    type Future<'a>: Future<Output = Result<(), Self::Error>>
+    where
+        Self: 'a;
+
+    /// Starts calibrating the device, returning a future that resolves once calibration has
+    /// finished.
+    ///
+    /// The device should generally be kept still until the future resolves.
+    fn calibrate(&mut self) -> Self::Future<'_>;
+}
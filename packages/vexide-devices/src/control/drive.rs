@@ -0,0 +1,73 @@
+//! Drivetrain input mixing.
+//!
+//! These functions convert driver inputs into left and right side outputs for a differential
+//! (tank-style) drivetrain. Outputs range from -1.0 to 1.0, and can be multiplied by a motor's
+//! [maximum voltage](crate::smart::motor::Motor::max_voltage) to get a voltage command.
+//!
+//! # Turning Convention
+//!
+//! Positive `turn` and `curvature` values turn the robot **clockwise** (to the right), matching
+//! the direction of the controller's joystick x-axis.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use vexide_devices::{control::drive, controller::Controller, smart::motor::Motor};
+//!
+//! fn drive(controller: &Controller, left: &mut Motor, right: &mut Motor) {
+//!     let state = controller.state().unwrap_or_default();
+//!     let (l, r) = drive::arcade(state.left_stick.y(), state.right_stick.x());
+//!
+//!     left.set_voltage(l * left.max_voltage()).ok();
+//!     right.set_voltage(r * right.max_voltage()).ok();
+//! }
+//! ```
+
+use vexide_core::float::Float;
+
+/// Scales a pair of outputs down proportionally so that neither exceeds a magnitude of 1.0.
+///
+/// This keeps the ratio between the two sides (and therefore the shape of the robot's path) intact,
+/// unlike clamping each side separately.
+fn desaturate(left: f64, right: f64) -> (f64, f64) {
+    let max = left.abs().max(right.abs());
+
+    if max > 1.0 {
+        (left / max, right / max)
+    } else {
+        (left, right)
+    }
+}
+
+/// Computes left and right outputs from independent inputs for each side of the drivetrain.
+///
+/// Inputs are clamped to -1.0..=1.0.
+pub fn tank(left: f64, right: f64) -> (f64, f64) {
+    (left.clamp(-1.0, 1.0), right.clamp(-1.0, 1.0))
+}
+
+/// Computes left and right outputs from a forward `throttle` and a `turn` rate.
+///
+/// If the combined outputs would exceed the -1.0..=1.0 range, both sides are scaled down
+/// proportionally.
+pub fn arcade(throttle: f64, turn: f64) -> (f64, f64) {
+    desaturate(throttle + turn, throttle - turn)
+}
+
+/// Computes left and right outputs using curvature drive (sometimes called "cheesy drive").
+///
+/// Unlike [`arcade`], `curvature` controls the *radius* of the robot's turn rather than its turn
+/// rate, so the robot follows the same arc regardless of how fast it is driving. Because this makes
+/// it impossible to turn in place, `quick_turn` can be set to fall back to arcade-style turning.
+///
+/// If the combined outputs would exceed the -1.0..=1.0 range, both sides are scaled down
+/// proportionally.
+pub fn curvature(throttle: f64, curvature: f64, quick_turn: bool) -> (f64, f64) {
+    let turn = if quick_turn {
+        curvature
+    } else {
+        throttle.abs() * curvature
+    };
+
+    desaturate(throttle + turn, throttle - turn)
+}
@@ -0,0 +1,304 @@
+//! Flywheel velocity estimation and control.
+//!
+//! Differentiating a flywheel's position reading to get its speed amplifies sensor noise, while
+//! heavily smoothing the result adds lag that makes RPM control sluggish. [`FlywheelVelocityEstimator`]
+//! uses an [alpha-beta filter], which tracks both position and velocity and corrects its
+//! prediction by a fraction of each new reading's error. Because it predicts where the flywheel
+//! should be at each step, it follows steady acceleration (such as spin-up) without the lag of a
+//! moving average or exponential filter.
+//!
+//! [`FlywheelController`] turns a target RPM and a measured velocity into a motor voltage. Most of
+//! the output comes from a feedforward term that is proportional to the target speed, which holds
+//! the flywheel near its target on its own. A PID loop on top of it corrects the remaining error,
+//! so that the flywheel recovers quickly when a shot slows it down.
+//!
+//! [alpha-beta filter]: https://en.wikipedia.org/wiki/Alpha_beta_filter
+//!
+//! # Example
+//!
+//! ```no_run
+//! use vexide_devices::{
+//!     control::flywheel::{FlywheelController, FlywheelVelocityEstimator},
+//!     smart::{motor::Motor, rotation::RotationSensor},
+//! };
+//!
+//! fn control(
+//!     sensor: &RotationSensor,
+//!     motor: &mut Motor,
+//!     estimator: &mut FlywheelVelocityEstimator,
+//!     controller: &mut FlywheelController,
+//! ) {
+//!     let rpm = estimator.update(sensor.position().unwrap());
+//!     println!("Flywheel speed: {rpm:.0} RPM");
+//!
+//!     motor.set_voltage(controller.update(rpm)).unwrap();
+//! }
+//! ```
+
+use vexide_core::{float::Float, time::Instant};
+
+use crate::position::Position;
+
+/// Estimates a flywheel's velocity from its position over time.
+///
+/// See the [module-level documentation](self) for more information.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlywheelVelocityEstimator {
+    alpha: f64,
+    beta: f64,
+    /// The estimated position in revolutions.
+    position: f64,
+    /// The estimated velocity in revolutions per second.
+    velocity: f64,
+    initialized: bool,
+    last_update: Option<Instant>,
+}
+
+impl FlywheelVelocityEstimator {
+    /// The default position gain, which works well for flywheels measured by a rotation sensor
+    /// every 10 to 20 milliseconds.
+    pub const DEFAULT_ALPHA: f64 = 0.5;
+
+    /// Creates a new estimator with the given position gain.
+    ///
+    /// `alpha` is clamped to the range `(0.0, 1.0)`. Higher values follow new readings more
+    /// closely and react faster, while lower values filter out more noise. The velocity gain is
+    /// derived from `alpha` so that the filter is critically damped, meaning that it settles
+    /// on a new speed as quickly as possible without overshooting.
+    pub fn new(alpha: f64) -> Self {
+        let alpha = alpha.clamp(0.01, 0.99);
+        let beta = 2.0 * (2.0 - alpha) - 4.0 * (1.0 - alpha).sqrt();
+
+        Self::with_gains(alpha, beta)
+    }
+
+    /// Creates a new estimator with explicitly chosen position (`alpha`) and velocity (`beta`)
+    /// gains.
+    ///
+    /// Both gains should be between `0.0` and `1.0`, and `beta` should usually be much smaller
+    /// than `alpha`. Prefer [`FlywheelVelocityEstimator::new`] unless the filter needs to be tuned
+    /// by hand.
+    pub const fn with_gains(alpha: f64, beta: f64) -> Self {
+        Self {
+            alpha,
+            beta,
+            position: 0.0,
+            velocity: 0.0,
+            initialized: false,
+            last_update: None,
+        }
+    }
+
+    /// Returns the position gain of the filter.
+    pub const fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Returns the velocity gain of the filter.
+    pub const fn beta(&self) -> f64 {
+        self.beta
+    }
+
+    /// Returns the current velocity estimate in RPM.
+    pub fn velocity(&self) -> f64 {
+        self.velocity * 60.0
+    }
+
+    /// Updates the estimate with a new position reading, returning the new velocity estimate in
+    /// RPM.
+    ///
+    /// The first reading after creating or [resetting](FlywheelVelocityEstimator::reset) the
+    /// estimator only sets its starting position, so the estimate stays at zero until the second
+    /// reading.
+    pub fn update(&mut self, position: Position) -> f64 {
+        let now = Instant::now();
+        let dt = self
+            .last_update
+            .map(|last| (now - last).as_secs_f64())
+            .unwrap_or_default();
+        self.last_update = Some(now);
+
+        self.update_with_dt(position, dt)
+    }
+
+    /// Updates the estimate with a new position reading and an explicitly provided time delta (in
+    /// seconds) since the previous reading, returning the new velocity estimate in RPM.
+    pub fn update_with_dt(&mut self, position: Position, dt: f64) -> f64 {
+        let measured = position.as_revolutions();
+
+        if !self.initialized {
+            self.position = measured;
+            self.initialized = true;
+            return self.velocity();
+        }
+
+        if dt <= 0.0 {
+            return self.velocity();
+        }
+
+        let predicted = self.position + self.velocity * dt;
+        let residual = measured - predicted;
+
+        self.position = predicted + self.alpha * residual;
+        self.velocity += self.beta * residual / dt;
+
+        self.velocity()
+    }
+
+    /// Resets the estimate to zero, forgetting the previous position and time.
+    pub fn reset(&mut self) {
+        self.position = 0.0;
+        self.velocity = 0.0;
+        self.initialized = false;
+        self.last_update = None;
+    }
+}
+
+impl Default for FlywheelVelocityEstimator {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_ALPHA)
+    }
+}
+
+/// Controls a flywheel's speed by combining feedforward with PID feedback.
+///
+/// The output voltage is `kv * target + kp * error + ki * integral + kd * derivative`, clamped to
+/// the controller's maximum voltage. To tune the controller:
+///
+/// 1. With all other gains at zero, raise `kv` until the flywheel settles close to (but slightly
+///    below) the target speed. A good starting point is the maximum voltage divided by the
+///    flywheel's free speed in RPM.
+/// 2. Raise `kp` until the flywheel recovers quickly after a shot without oscillating.
+/// 3. Add a small `ki` to remove any remaining steady-state error.
+/// 4. `kd` is rarely needed for flywheels, and amplifies noise in the velocity measurement.
+///
+/// The derivative term acts on the measured velocity rather than the error, so changing the target
+/// doesn't cause a sudden spike in output. The integral only accumulates while the output isn't
+/// saturated, so that it doesn't wind up during spin-up.
+///
+/// See the [module-level documentation](self) for an example.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlywheelController {
+    kv: f64,
+    kp: f64,
+    ki: f64,
+    kd: f64,
+    max_voltage: f64,
+    /// The target speed in RPM.
+    target: f64,
+    /// The accumulated error in RPM-seconds.
+    integral: f64,
+    /// The previous measured speed in RPM.
+    previous: Option<f64>,
+    last_update: Option<Instant>,
+}
+
+impl FlywheelController {
+    /// Creates a new controller with a feedforward gain (`kv`, in volts per RPM) and PID gains.
+    ///
+    /// The target starts at zero, and the output is limited to [`Motor::V5_MAX_VOLTAGE`].
+    ///
+    /// [`Motor::V5_MAX_VOLTAGE`]: crate::smart::motor::Motor::V5_MAX_VOLTAGE
+    pub const fn new(kv: f64, kp: f64, ki: f64, kd: f64) -> Self {
+        Self {
+            kv,
+            kp,
+            ki,
+            kd,
+            max_voltage: crate::smart::motor::Motor::V5_MAX_VOLTAGE,
+            target: 0.0,
+            integral: 0.0,
+            previous: None,
+            last_update: None,
+        }
+    }
+
+    /// Sets the largest voltage (in either direction) that the controller will output.
+    ///
+    /// This should be set to [`Motor::EXP_MAX_VOLTAGE`] when controlling a 5.5W motor.
+    ///
+    /// [`Motor::EXP_MAX_VOLTAGE`]: crate::smart::motor::Motor::EXP_MAX_VOLTAGE
+    #[must_use]
+    pub const fn with_max_voltage(mut self, max_voltage: f64) -> Self {
+        self.max_voltage = max_voltage;
+        self
+    }
+
+    /// Returns the target speed in RPM.
+    pub const fn target(&self) -> f64 {
+        self.target
+    }
+
+    /// Sets the target speed in RPM.
+    pub fn set_target(&mut self, rpm: f64) {
+        self.target = rpm;
+    }
+
+    /// Returns the difference between the target speed and the last measured speed in RPM, or
+    /// [`None`] if the controller hasn't been updated yet.
+    pub fn error(&self) -> Option<f64> {
+        self.previous.map(|measured| self.target - measured)
+    }
+
+    /// Returns `true` if the last measured speed was within `tolerance` RPM of the target.
+    ///
+    /// This can be used to wait for the flywheel to recover before taking the next shot.
+    pub fn is_at_target(&self, tolerance: f64) -> bool {
+        self.error().is_some_and(|error| error.abs() <= tolerance)
+    }
+
+    /// Computes the motor voltage needed to reach the target speed given the measured speed in
+    /// RPM.
+    ///
+    /// The time since the previous update is measured automatically. On the first update after
+    /// creating or [resetting](FlywheelController::reset) the controller, only the feedforward and
+    /// proportional terms are used.
+    pub fn update(&mut self, measured: f64) -> f64 {
+        let now = Instant::now();
+        let dt = self
+            .last_update
+            .map(|last| (now - last).as_secs_f64())
+            .unwrap_or_default();
+        self.last_update = Some(now);
+
+        self.update_with_dt(measured, dt)
+    }
+
+    /// Computes the motor voltage needed to reach the target speed given the measured speed in RPM
+    /// and an explicitly provided time delta (in seconds) since the previous update.
+    pub fn update_with_dt(&mut self, measured: f64, dt: f64) -> f64 {
+        let error = self.target - measured;
+
+        let derivative = match self.previous {
+            Some(previous) if dt > 0.0 => -(measured - previous) / dt,
+            _ => 0.0,
+        };
+        self.previous = Some(measured);
+
+        let output_without_integral =
+            self.kv * self.target + self.kp * error + self.kd * derivative;
+
+        if dt > 0.0 {
+            let integral = self.integral + error * dt;
+            let output = output_without_integral + self.ki * integral;
+
+            // Only accumulate error while it can still change the output.
+            if output.abs() < self.max_voltage || output.signum() != error.signum() {
+                self.integral = integral;
+            }
+        }
+
+        (output_without_integral + self.ki * self.integral)
+            .clamp(-self.max_voltage, self.max_voltage)
+    }
+
+    /// Clears the accumulated error and previous measurement, keeping the gains and target.
+    ///
+    /// This should be called when the flywheel starts being controlled again after a pause, so that
+    /// stale state from the previous run doesn't affect the output.
+    pub fn reset(&mut self) {
+        self.integral = 0.0;
+        self.previous = None;
+        self.last_update = None;
+    }
+}
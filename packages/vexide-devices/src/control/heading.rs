@@ -0,0 +1,92 @@
+//! Heading sensor fusion.
+//!
+//! Inertial sensors slowly drift over the course of a match, so advanced teams often combine
+//! readings from several heading sources, such as two [`InertialSensor`]s or an inertial sensor and
+//! a [`GpsSensor`]. [`fuse_headings`] computes a weighted average of these readings.
+//!
+//! Headings can't be averaged directly, since they wrap around at 360 degrees: the average of 350°
+//! and 10° should be 0°, not 180°. Instead, each heading is treated as a unit vector pointing in
+//! its direction, and the result is the direction of the weighted sum of those vectors (known as
+//! the *circular mean*).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use vexide_devices::control::heading::fuse_headings;
+//!
+//! // Trust the first IMU twice as much as the second.
+//! let heading = fuse_headings([(imu_a.heading()?, 2.0), (imu_b.heading()?, 1.0)]);
+//! ```
+//!
+//! [`InertialSensor`]: crate::smart::imu::InertialSensor
+//! [`GpsSensor`]: crate::smart::gps::GpsSensor
+
+use vexide_core::float::Float;
+
+/// Computes the weighted circular mean of a set of `(heading, weight)` readings in degrees.
+///
+/// All headings should use the same angle convention (for example, clockwise headings from
+/// [`InertialSensor::heading`](crate::smart::imu::InertialSensor::heading)). Readings with a weight
+/// of zero or less are ignored. The result is in the range `0.0..360.0`.
+///
+/// Returns `None` if there are no readings with a positive weight, or if the readings cancel each
+/// other out (such as two equally weighted headings facing opposite directions) so that there is no
+/// meaningful average.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide_devices::control::heading::fuse_headings;
+///
+/// let heading = fuse_headings([(350.0, 1.0), (10.0, 1.0)]).unwrap();
+/// assert!(heading < 1e-9 || heading > 360.0 - 1e-9);
+///
+/// assert!(fuse_headings([(0.0, 1.0), (180.0, 1.0)]).is_none());
+/// ```
+pub fn fuse_headings(readings: impl IntoIterator<Item = (f64, f64)>) -> Option<f64> {
+    let mut x = 0.0;
+    let mut y = 0.0;
+    let mut total_weight = 0.0;
+
+    for (heading, weight) in readings {
+        if weight <= 0.0 {
+            continue;
+        }
+
+        let (sin, cos) = heading.to_radians().sin_cos();
+        x += cos * weight;
+        y += sin * weight;
+        total_weight += weight;
+    }
+
+    // If the sum of the vectors is (nearly) zero, its direction is meaningless.
+    if total_weight <= 0.0 || x.hypot(y) <= total_weight * 1e-9 {
+        return None;
+    }
+
+    Some(y.atan2(x).to_degrees().rem_euclid(360.0))
+}
+
+/// Returns the signed difference from heading `from` to heading `to` in degrees, taking the
+/// shortest way around the circle.
+///
+/// The result is in the range `-180.0..=180.0`, and is positive if `to` is reached by increasing
+/// `from`. This is useful for measuring how far two heading sources have drifted apart.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vexide_devices::control::heading::angle_difference;
+///
+/// assert_eq!(angle_difference(350.0, 10.0), 20.0);
+/// assert_eq!(angle_difference(10.0, 350.0), -20.0);
+/// ```
+pub fn angle_difference(from: f64, to: f64) -> f64 {
+    let difference = (to - from).rem_euclid(360.0);
+
+    if difference > 180.0 {
+        difference - 360.0
+    } else {
+        difference
+    }
+}
@@ -0,0 +1,102 @@
+//! Driver input shaping.
+//!
+//! Raw joystick values jitter slightly around the center and map linearly to output, which can make
+//! fine adjustments difficult. [`InputCurve`] applies a deadzone to ignore small values near the
+//! center, followed by an exponential curve that gives the driver more precision at low speeds.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use vexide_devices::{control::input::InputCurve, controller::Controller};
+//!
+//! let curve = InputCurve::new(0.05, 2.0);
+//!
+//! fn read(controller: &Controller, curve: &InputCurve) -> (f64, f64) {
+//!     let state = controller.state().unwrap_or_default();
+//!     curve.apply_joystick(&state.left_stick)
+//! }
+//! ```
+
+use vexide_core::float::Float;
+
+use crate::controller::JoystickState;
+
+/// A deadzone and exponential curve applied to driver input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InputCurve {
+    deadzone: f64,
+    exponent: f64,
+}
+
+impl InputCurve {
+    /// Creates a new input curve.
+    ///
+    /// - `deadzone` is the magnitude from `0.0` to `1.0` below which input is treated as zero.
+    /// - `exponent` is the power that input is raised to after the deadzone is removed. A value of
+    ///   `1.0` is linear, while larger values give finer control near the center of the joystick.
+    ///
+    /// The deadzone is clamped to [0.0, 1.0), and the exponent is clamped to be no less than zero.
+    pub fn new(deadzone: f64, exponent: f64) -> Self {
+        Self {
+            deadzone: deadzone.clamp(0.0, 1.0 - f64::EPSILON),
+            exponent: exponent.max(0.0),
+        }
+    }
+
+    /// Creates an input curve with no deadzone that passes input through unchanged.
+    pub const fn linear() -> Self {
+        Self {
+            deadzone: 0.0,
+            exponent: 1.0,
+        }
+    }
+
+    /// Returns the deadzone of this curve.
+    pub const fn deadzone(&self) -> f64 {
+        self.deadzone
+    }
+
+    /// Sets the deadzone of this curve, clamped to [0.0, 1.0).
+    pub fn set_deadzone(&mut self, deadzone: f64) {
+        self.deadzone = deadzone.clamp(0.0, 1.0 - f64::EPSILON);
+    }
+
+    /// Returns the exponent of this curve.
+    pub const fn exponent(&self) -> f64 {
+        self.exponent
+    }
+
+    /// Sets the exponent of this curve, clamped to be no less than zero.
+    pub fn set_exponent(&mut self, exponent: f64) {
+        self.exponent = exponent.max(0.0);
+    }
+
+    /// Applies the curve to an input from -1.0 to 1.0.
+    ///
+    /// Values inside the deadzone return `0.0`. Values outside of it are rescaled so that output
+    /// smoothly starts from zero at the edge of the deadzone, then are raised to the curve's exponent
+    /// while preserving their sign.
+    pub fn apply(&self, input: f64) -> f64 {
+        let input = input.clamp(-1.0, 1.0);
+        let magnitude = input.abs();
+
+        if magnitude <= self.deadzone {
+            return 0.0;
+        }
+
+        let scaled = (magnitude - self.deadzone) / (1.0 - self.deadzone);
+
+        scaled.powf(self.exponent).copysign(input)
+    }
+
+    /// Applies the curve to each axis of a joystick, returning the shaped `(x, y)` values.
+    pub fn apply_joystick(&self, joystick: &JoystickState) -> (f64, f64) {
+        (self.apply(joystick.x()), self.apply(joystick.y()))
+    }
+}
+
+impl Default for InputCurve {
+    fn default() -> Self {
+        Self::linear()
+    }
+}
@@ -0,0 +1,144 @@
+//! Running mechanisms until a sensor is triggered.
+//!
+//! A very common autonomous routine is running an intake until a sensor sees a game piece, giving
+//! up if it takes too long. [`run_until`] handles this, including stopping the motors when the
+//! routine finishes, times out, or is cancelled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use vexide_devices::control::intake::run_until;
+//!
+//! let picked_up = run_until(&mut intake_motors, 12.0, Duration::from_secs(2), || {
+//!     distance
+//!         .object()
+//!         .ok()
+//!         .flatten()
+//!         .is_some_and(|object| object.distance < 50)
+//! })
+//! .await?;
+//!
+//! if !picked_up {
+//!     println!("Missed the game piece!");
+//! }
+//! ```
+
+use core::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use vexide_core::time::Instant;
+
+use crate::smart::motor::{BrakeMode, Motor, MotorError};
+
+/// Runs a group of motors at `voltage` until `predicate` returns `true` or `timeout` elapses.
+///
+/// The returned future resolves to `Ok(true)` if the predicate was satisfied, or `Ok(false)` if the
+/// timeout elapsed first. The predicate is checked every time the future is polled, which is
+/// typically once per executor loop.
+///
+/// The motors are braked when the future resolves, and also if it is dropped before resolving
+/// (such as when it loses a `select!` or the autonomous period ends), so they are never left
+/// running.
+///
+/// # Errors
+///
+/// Returns an error if any of the motors can't be commanded. The motors are still braked.
+pub fn run_until<P: FnMut() -> bool + Unpin>(
+    motors: &mut [Motor],
+    voltage: f64,
+    timeout: Duration,
+    predicate: P,
+) -> RunUntilFuture<'_, P> {
+    RunUntilFuture {
+        motors,
+        voltage,
+        timeout,
+        predicate,
+        started: None,
+        finished: false,
+    }
+}
+
+/// A future that runs a group of motors until a condition is met.
+///
+/// See [`run_until`] for more information.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct RunUntilFuture<'a, P> {
+    motors: &'a mut [Motor],
+    voltage: f64,
+    timeout: Duration,
+    predicate: P,
+    started: Option<Instant>,
+    finished: bool,
+}
+
+impl<P> RunUntilFuture<'_, P> {
+    /// Brakes every motor, returning the first error encountered.
+    fn stop(&mut self) -> Result<(), MotorError> {
+        self.finished = true;
+
+        let mut result = Ok(());
+        for motor in self.motors.iter_mut() {
+            let braked = motor.brake(BrakeMode::Brake);
+            if result.is_ok() {
+                result = braked;
+            }
+        }
+        result
+    }
+}
+
+impl<P: FnMut() -> bool + Unpin> Future for RunUntilFuture<'_, P> {
+    type Output = Result<bool, MotorError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+
+        let started = match this.started {
+            Some(started) => started,
+            None => {
+                for motor in this.motors.iter_mut() {
+                    if let Err(err) = motor.set_voltage(this.voltage) {
+                        _ = this.stop();
+                        return Poll::Ready(Err(err));
+                    }
+                }
+
+                *this.started.insert(Instant::now())
+            }
+        };
+
+        let satisfied = (this.predicate)();
+        if satisfied || started.elapsed() >= this.timeout {
+            return Poll::Ready(this.stop().map(|()| satisfied));
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+impl<P> Drop for RunUntilFuture<'_, P> {
+    fn drop(&mut self) {
+        if self.started.is_some() && !self.finished {
+            _ = self.stop();
+        }
+    }
+}
+
+impl<P> fmt::Debug for RunUntilFuture<'_, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RunUntilFuture")
+            .field("motors", &self.motors)
+            .field("voltage", &self.voltage)
+            .field("timeout", &self.timeout)
+            .field("started", &self.started)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,22 @@
+//! Control theory utilities.
+//!
+//! This module contains helpers for computing smooth setpoints and motor outputs that
+//! are commonly used when writing autonomous routines.
+//!
+//! - [`drive`] mixes driver inputs into drivetrain outputs.
+//! - [`flywheel`] estimates and controls flywheel velocity.
+//! - [`heading`] fuses heading readings from multiple sensors.
+//! - [`input`] shapes driver joystick input using deadzones and curves.
+//! - [`intake`] runs motors until a sensor condition is met.
+//! - [`odometry`] tracks the robot's position on the field using tracking wheels.
+//! - [`profile`] generates trapezoidal velocity profiles for point-to-point moves.
+//! - [`slew`] limits how quickly a signal such as motor voltage may change.
+
+pub mod drive;
+pub mod flywheel;
+pub mod heading;
+pub mod input;
+pub mod intake;
+pub mod odometry;
+pub mod profile;
+pub mod slew;
@@ -0,0 +1,152 @@
+//! Position tracking.
+//!
+//! Odometry estimates the robot's position on the field by integrating small changes in
+//! tracking wheel distances along with the robot's heading. This module provides an
+//! [`Odometry`] tracker that combines parallel (left and right) tracking wheels, an optional
+//! perpendicular (lateral) tracking wheel, and a heading reading from an
+//! [`InertialSensor`](crate::smart::imu::InertialSensor).
+//!
+//! # Coordinate System
+//!
+//! Positions are reported in a standard cartesian frame, and headings follow the same
+//! convention as [`GpsSensor::pose`](crate::smart::gps::GpsSensor::pose): degrees that
+//! **increase counterclockwise**, with `0` facing the positive x axis. This makes the pose
+//! directly usable with trig functions.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use vexide_devices::{
+//!     control::odometry::{Odometry, Pose},
+//!     geometry::Point2,
+//!     smart::{imu::InertialSensor, rotation::RotationSensor},
+//! };
+//!
+//! const WHEEL_CIRCUMFERENCE: f64 = 2.75 * core::f64::consts::PI;
+//!
+//! fn track(left: &RotationSensor, right: &RotationSensor, imu: &InertialSensor) {
+//!     let mut odom = Odometry::new(Pose::new(Point2::new(0.0, 0.0), 90.0), 0.0);
+//!     let mut last = (0.0, 0.0);
+//!
+//!     loop {
+//!         let l = left.position().unwrap().as_revolutions() * WHEEL_CIRCUMFERENCE;
+//!         let r = right.position().unwrap().as_revolutions() * WHEEL_CIRCUMFERENCE;
+//!
+//!         odom.update(l - last.0, r - last.1, None, imu.rotation().unwrap());
+//!         last = (l, r);
+//!     }
+//! }
+//! ```
+
+use vexide_core::float::Float;
+
+use crate::geometry::{Point2, Transform2D};
+
+/// A position and heading on the field.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Pose {
+    /// The position of the robot's tracking center.
+    pub position: Point2<f64>,
+
+    /// The robot's heading in degrees, increasing counterclockwise.
+    pub heading: f64,
+}
+
+impl Pose {
+    /// Creates a new pose from a position and a counterclockwise heading in degrees.
+    pub const fn new(position: Point2<f64>, heading: f64) -> Self {
+        Self { position, heading }
+    }
+}
+
+impl From<Pose> for Transform2D {
+    /// Returns the transform from the robot frame to the field frame.
+    fn from(pose: Pose) -> Self {
+        Transform2D::new(pose.position, pose.heading)
+    }
+}
+
+/// Tracks the robot's pose using tracking wheels and an IMU heading.
+///
+/// The left and right wheels are expected to be parallel to the robot's direction of travel
+/// and equally spaced from its center of rotation, such that their average reports only the
+/// forward motion of the robot. An optional lateral wheel perpendicular to them measures
+/// sideways motion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Odometry {
+    pose: Pose,
+    lateral_offset: f64,
+    previous_rotation: Option<f64>,
+}
+
+impl Odometry {
+    /// Creates a new tracker starting at `initial_pose`.
+    ///
+    /// `lateral_offset` is the signed distance from the center of rotation to the lateral
+    /// tracking wheel along the robot's forward axis, where positive values are in front of
+    /// the center. Pass `0.0` if there is no lateral wheel.
+    pub const fn new(initial_pose: Pose, lateral_offset: f64) -> Self {
+        Self {
+            pose: initial_pose,
+            lateral_offset,
+            previous_rotation: None,
+        }
+    }
+
+    /// Returns the current estimated pose of the robot.
+    pub const fn pose(&self) -> Pose {
+        self.pose
+    }
+
+    /// Overrides the current estimated pose of the robot.
+    ///
+    /// The next call to [`Odometry::update`] will treat its rotation reading as matching
+    /// this pose's heading.
+    pub fn set_pose(&mut self, pose: Pose) {
+        self.pose = pose;
+        self.previous_rotation = None;
+    }
+
+    /// Integrates a new set of tracking wheel measurements into the pose estimate.
+    ///
+    /// - `left` and `right` are the distances traveled by each parallel tracking wheel since
+    ///   the last update. Forward motion is positive.
+    /// - `lateral` is the distance traveled by the perpendicular tracking wheel since the last
+    ///   update, if one is present. Motion to the robot's right is positive.
+    /// - `rotation` is the robot's current rotation in degrees as reported by
+    ///   [`InertialSensor::rotation`](crate::smart::imu::InertialSensor::rotation), with
+    ///   **clockwise** rotations being positive.
+    ///
+    /// Distances may be in any unit, which will be the unit of the resulting position. This
+    /// should be called as often as possible, usually once per loop iteration.
+    pub fn update(&mut self, left: f64, right: f64, lateral: Option<f64>, rotation: f64) {
+        // Convert from the IMU's clockwise convention into counterclockwise radians.
+        let delta_heading = self
+            .previous_rotation
+            .map(|previous| -(rotation - previous).to_radians())
+            .unwrap_or_default();
+        self.previous_rotation = Some(rotation);
+
+        let forward = (left + right) / 2.0;
+
+        // Turning in place moves an offset lateral wheel even if the robot doesn't translate,
+        // so that portion of its reading is removed here.
+        let lateral = lateral
+            .map(|lateral| lateral + self.lateral_offset * delta_heading)
+            .unwrap_or_default();
+
+        // Assume the robot moved along a circular arc, and find the chord length of that arc.
+        let chord_scale = if delta_heading == 0.0 {
+            1.0
+        } else {
+            2.0 * (delta_heading / 2.0).sin() / delta_heading
+        };
+
+        let average_heading = self.pose.heading.to_radians() + delta_heading / 2.0;
+        let (sin, cos) = average_heading.sin_cos();
+
+        self.pose.position.x += chord_scale * (forward * cos + lateral * sin);
+        self.pose.position.y += chord_scale * (forward * sin - lateral * cos);
+        self.pose.heading += delta_heading.to_degrees();
+    }
+}
@@ -0,0 +1,172 @@
+//! Motion profiles.
+//!
+//! A motion profile describes how a mechanism should travel a given distance over time
+//! while respecting limits on its velocity and acceleration. Rather than asking a
+//! controller to jump instantly to a far-away target, a profile can be sampled every loop
+//! iteration to produce intermediate position and velocity setpoints.
+//!
+//! # Example
+//!
+//! ```
+//! use core::time::Duration;
+//! use vexide_devices::control::profile::TrapezoidalProfile;
+//!
+//! // Move 48 inches with a max velocity of 24 in/s and a max acceleration of 48 in/s^2.
+//! let profile = TrapezoidalProfile::new(48.0, 24.0, 48.0);
+//!
+//! let state = profile.state(Duration::from_millis(500));
+//! assert_eq!(state.velocity, 24.0);
+//! ```
+
+use core::time::Duration;
+
+use vexide_core::float::Float;
+
+/// A sample of a motion profile at a single point in time.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct ProfileState {
+    /// Target position relative to the start of the move.
+    pub position: f64,
+
+    /// Target velocity in units per second.
+    pub velocity: f64,
+
+    /// Target acceleration in units per second squared.
+    pub acceleration: f64,
+}
+
+/// A trapezoidal velocity profile.
+///
+/// The profile accelerates at a constant rate until it reaches its maximum velocity,
+/// cruises at that velocity, then decelerates at the same rate to stop exactly at the
+/// target distance. If the distance is too short to ever reach the maximum velocity, the
+/// cruise phase is skipped and the profile becomes triangular, peaking at a lower velocity.
+///
+/// Units are arbitrary, but must be consistent; if distance is given in inches and
+/// time is measured in seconds, then velocity must be in inches per second and
+/// acceleration must be in inches per second squared.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrapezoidalProfile {
+    distance: f64,
+    acceleration: f64,
+    peak_velocity: f64,
+    accel_time: f64,
+    cruise_time: f64,
+}
+
+impl TrapezoidalProfile {
+    /// Creates a new profile that travels `distance` units without exceeding
+    /// `max_velocity` or `max_acceleration`.
+    ///
+    /// `distance` may be negative to move in the reverse direction. The signs of
+    /// `max_velocity` and `max_acceleration` are ignored.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `max_velocity` or `max_acceleration` is zero.
+    pub fn new(distance: f64, max_velocity: f64, max_acceleration: f64) -> Self {
+        let max_velocity = max_velocity.abs();
+        let max_acceleration = max_acceleration.abs();
+
+        assert!(
+            max_velocity != 0.0 && max_acceleration != 0.0,
+            "Motion profile limits must be non-zero."
+        );
+
+        let magnitude = distance.abs();
+
+        // Distance covered while accelerating from zero to max velocity (and the same again
+        // while decelerating back to zero).
+        let accel_distance = max_velocity * max_velocity / (2.0 * max_acceleration);
+
+        let (peak_velocity, accel_time, cruise_time) = if 2.0 * accel_distance > magnitude {
+            // Triangular profile; we run out of room before reaching max velocity.
+            let peak_velocity = (magnitude * max_acceleration).sqrt();
+            (peak_velocity, peak_velocity / max_acceleration, 0.0)
+        } else {
+            (
+                max_velocity,
+                max_velocity / max_acceleration,
+                (magnitude - 2.0 * accel_distance) / max_velocity,
+            )
+        };
+
+        Self {
+            distance,
+            acceleration: max_acceleration,
+            peak_velocity,
+            accel_time,
+            cruise_time,
+        }
+    }
+
+    /// Returns the total distance traveled by this profile.
+    pub const fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    /// Returns the highest velocity reached during the profile.
+    ///
+    /// This will be less than the maximum velocity passed to [`TrapezoidalProfile::new`] if the
+    /// profile is triangular.
+    pub fn peak_velocity(&self) -> f64 {
+        self.peak_velocity.copysign(self.distance)
+    }
+
+    /// Returns `true` if the move is too short to reach its maximum velocity.
+    pub fn is_triangular(&self) -> bool {
+        self.cruise_time == 0.0
+    }
+
+    /// Returns the total time needed to complete the profile.
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f64(2.0 * self.accel_time + self.cruise_time)
+    }
+
+    /// Returns `true` if the profile has finished by the time `t` has elapsed since its start.
+    pub fn is_finished(&self, t: Duration) -> bool {
+        t >= self.duration()
+    }
+
+    /// Samples the target position, velocity, and acceleration after `t` has elapsed since
+    /// the start of the profile.
+    ///
+    /// Times past the end of the profile return the final position with zero velocity.
+    pub fn state(&self, t: Duration) -> ProfileState {
+        let t = t.as_secs_f64();
+        let accel = self.acceleration;
+        let decel_start = self.accel_time + self.cruise_time;
+        let total_time = decel_start + self.accel_time;
+
+        let accel_distance = 0.5 * accel * self.accel_time * self.accel_time;
+
+        let (position, velocity, acceleration) = if t < self.accel_time {
+            (0.5 * accel * t * t, accel * t, accel)
+        } else if t < decel_start {
+            let cruise_elapsed = t - self.accel_time;
+            (
+                accel_distance + self.peak_velocity * cruise_elapsed,
+                self.peak_velocity,
+                0.0,
+            )
+        } else if t < total_time {
+            let remaining = total_time - t;
+            (
+                self.distance.abs() - 0.5 * accel * remaining * remaining,
+                accel * remaining,
+                -accel,
+            )
+        } else {
+            (self.distance.abs(), 0.0, 0.0)
+        };
+
+        // Mirror the profile for moves in the negative direction.
+        let sign = if self.distance < 0.0 { -1.0 } else { 1.0 };
+
+        ProfileState {
+            position: position * sign,
+            velocity: velocity * sign,
+            acceleration: acceleration * sign,
+        }
+    }
+}
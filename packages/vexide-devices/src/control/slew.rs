@@ -0,0 +1,92 @@
+//! Slew-rate limiting.
+//!
+//! A slew-rate limiter caps how quickly a signal is allowed to change. Applying one to a
+//! motor's commanded voltage smooths out sudden jumps in output that would otherwise cause
+//! wheel slip or tip the robot over.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use vexide_devices::{control::slew::SlewRateLimiter, smart::motor::Motor};
+//!
+//! fn drive(motor: &mut Motor, limiter: &mut SlewRateLimiter, volts: f64) {
+//!     // Output will ramp towards `volts` at no more than 24 volts per second.
+//!     motor.set_voltage(limiter.calculate(volts)).ok();
+//! }
+//! ```
+
+use vexide_core::{float::Float, time::Instant};
+
+/// Limits the rate of change of a signal.
+///
+/// The limiter tracks the last value it output along with the time it was produced,
+/// allowing it to scale the maximum change by the time that has passed between calls.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlewRateLimiter {
+    rate: f64,
+    previous: f64,
+    last_update: Option<Instant>,
+}
+
+impl SlewRateLimiter {
+    /// Creates a new limiter that allows its output to change by at most `rate` units per
+    /// second.
+    ///
+    /// The sign of `rate` is ignored.
+    pub fn new(rate: f64) -> Self {
+        Self {
+            rate: rate.abs(),
+            previous: 0.0,
+            last_update: None,
+        }
+    }
+
+    /// Returns the maximum rate of change in units per second.
+    pub const fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Sets the maximum rate of change in units per second.
+    ///
+    /// The sign of `rate` is ignored.
+    pub fn set_rate(&mut self, rate: f64) {
+        self.rate = rate.abs();
+    }
+
+    /// Returns the last value output by the limiter.
+    pub const fn previous(&self) -> f64 {
+        self.previous
+    }
+
+    /// Computes the next output of the limiter, moving from the previous output towards
+    /// `input` by no more than the configured rate allows.
+    ///
+    /// The first call after creating or [resetting](SlewRateLimiter::reset) the limiter
+    /// has no time delta to work with and is limited as if no time has passed.
+    pub fn calculate(&mut self, input: f64) -> f64 {
+        let now = Instant::now();
+        let dt = self
+            .last_update
+            .map(|last| (now - last).as_secs_f64())
+            .unwrap_or_default();
+        self.last_update = Some(now);
+
+        self.calculate_with_dt(input, dt)
+    }
+
+    /// Computes the next output of the limiter using an explicitly provided time delta (in
+    /// seconds) since the previous call.
+    pub fn calculate_with_dt(&mut self, input: f64, dt: f64) -> f64 {
+        let max_change = self.rate * dt;
+        let change = (input - self.previous).clamp(-max_change, max_change);
+
+        self.previous += change;
+        self.previous
+    }
+
+    /// Resets the limiter to output `value`, forgetting its previous time delta.
+    pub fn reset(&mut self, value: f64) {
+        self.previous = value;
+        self.last_update = None;
+    }
+}
@@ -9,6 +9,9 @@ pub use mint::{EulerAngles, Quaternion, Vector3};
 
 #[cfg(feature = "nalgebra")]
 mod nalgebra;
+pub mod transform;
+
+pub use transform::Transform2D;
 
 /// A point in 2D cartesian space.
 #[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
@@ -0,0 +1,158 @@
+//! Coordinate frames and conversions between them.
+//!
+//! Different devices report positions in different coordinate systems, which makes it easy to mix
+//! up signs and origins when combining their data. vexide uses the following frames:
+//!
+//! - **Field frame**: Absolute positions on the field, as reported by
+//!   [`GpsSensor::pose`](crate::smart::gps::GpsSensor::pose) (in meters, from the center of the
+//!   field) and tracked by [`Odometry`](crate::control::odometry::Odometry) (in whatever units its
+//!   wheel distances are given in). Headings are in degrees and **increase counterclockwise**,
+//!   with `0` facing the positive x axis.
+//! - **Robot frame**: Positions relative to the robot's tracking center, with **+x pointing
+//!   forward** and **+y pointing to the robot's left**. Angles are measured the same way as in the
+//!   field frame, so a heading of `0` is straight ahead and `90` is directly to the left.
+//! - **Sensor pixel frame**: Positions within a camera's image, such as
+//!   [`VisionObject::center`](crate::smart::vision::VisionObject::center). The origin is the
+//!   **top-left** corner of the image, with +x to the right and **+y pointing down**. Note that
+//!   [`VisionObject::bearing`](crate::smart::vision::VisionObject::bearing) is positive to the
+//!   right, which is *clockwise*, so it must be negated to get a robot frame angle.
+//!
+//! A [`Transform2D`] describes where one frame sits within another, and converts positions and
+//! headings from the inner frame to the outer one. For example, the robot's pose is the transform
+//! from the robot frame to the field frame, and a sensor's mounting position is the transform
+//! from the sensor's frame to the robot frame. Transforms can be composed with
+//! [`Transform2D::then`] (or the `*` operator) to convert directly between any two frames.
+//!
+//! # Example
+//!
+//! Locating an object seen by a vision sensor on the field:
+//!
+//! ```no_run
+//! use vexide_devices::geometry::{transform::{self, Transform2D}, Point2};
+//!
+//! // The vision sensor is mounted 4 inches in front of the tracking center, facing forward.
+//! let camera_to_robot = Transform2D::new(Point2::new(4.0, 0.0), 0.0);
+//! let robot_to_field = Transform2D::from(odometry.pose());
+//!
+//! if let (Some(object), Some(reading)) = (vision.objects()?.first(), distance_sensor.object()?) {
+//!     // Negate the bearing, since it is positive clockwise.
+//!     let direction = -object.bearing();
+//!     let distance = reading.distance as f64 / 25.4;
+//!
+//!     let field_position = camera_to_robot
+//!         .then(robot_to_field)
+//!         .transform_point(transform::polar(distance, direction));
+//! }
+//! ```
+
+use core::ops::Mul;
+
+use vexide_core::float::Float;
+
+use super::Point2;
+
+/// Returns the point `distance` units away from the origin in the direction of `angle` degrees
+/// counterclockwise from the positive x axis.
+///
+/// This is useful for turning a sensor's bearing and range reading into a position in its
+/// frame, which can then be converted to other frames using a [`Transform2D`].
+pub fn polar(distance: f64, angle: f64) -> Point2<f64> {
+    let (sin, cos) = angle.to_radians().sin_cos();
+
+    Point2::new(distance * cos, distance * sin)
+}
+
+/// A rigid transformation (rotation followed by translation) between two 2D coordinate frames.
+///
+/// A transform describes the position and orientation of an inner frame (such as the robot or a
+/// sensor) within an outer frame (such as the field or the robot). Transforming a point maps
+/// coordinates in the inner frame to coordinates in the outer frame.
+///
+/// See the [module-level documentation](self) for the conventions used by vexide.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform2D {
+    /// The position of the inner frame's origin in the outer frame.
+    pub translation: Point2<f64>,
+
+    /// The rotation of the inner frame relative to the outer frame in degrees, increasing
+    /// counterclockwise.
+    pub rotation: f64,
+}
+
+impl Transform2D {
+    /// The transform that leaves points unchanged.
+    pub const IDENTITY: Self = Self::new(Point2::new(0.0, 0.0), 0.0);
+
+    /// Creates a transform for a frame with its origin at `translation` that is rotated `rotation`
+    /// degrees counterclockwise.
+    pub const fn new(translation: Point2<f64>, rotation: f64) -> Self {
+        Self {
+            translation,
+            rotation,
+        }
+    }
+
+    /// Converts a point in the inner frame to the outer frame.
+    pub fn transform_point(&self, point: Point2<f64>) -> Point2<f64> {
+        let (sin, cos) = self.rotation.to_radians().sin_cos();
+
+        Point2::new(
+            self.translation.x + point.x * cos - point.y * sin,
+            self.translation.y + point.x * sin + point.y * cos,
+        )
+    }
+
+    /// Converts a heading in the inner frame to the outer frame, wrapped to `[0, 360)` degrees.
+    pub fn transform_heading(&self, heading: f64) -> f64 {
+        (heading + self.rotation).rem_euclid(360.0)
+    }
+
+    /// Returns the transform that converts points from the outer frame back to the inner frame.
+    ///
+    /// For example, inverting the robot's pose gives a transform from field coordinates to robot
+    /// coordinates, which can be used to find where a field element is relative to the robot.
+    pub fn inverse(&self) -> Self {
+        let (sin, cos) = self.rotation.to_radians().sin_cos();
+        let Point2 { x, y } = self.translation;
+
+        Self::new(
+            Point2::new(-(x * cos + y * sin), x * sin - y * cos),
+            -self.rotation,
+        )
+    }
+
+    /// Returns a transform that applies this transform followed by `outer`.
+    ///
+    /// If `self` converts from frame A to frame B and `outer` converts from frame B to frame C,
+    /// the result converts from frame A to frame C. This is equivalent to `outer * self`.
+    pub fn then(&self, outer: Self) -> Self {
+        Self::new(
+            outer.transform_point(self.translation),
+            self.rotation + outer.rotation,
+        )
+    }
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mul for Transform2D {
+    type Output = Self;
+
+    /// Composes two transforms, applying `rhs` first.
+    fn mul(self, rhs: Self) -> Self::Output {
+        rhs.then(self)
+    }
+}
+
+impl Mul<Point2<f64>> for Transform2D {
+    type Output = Point2<f64>;
+
+    /// Converts a point in the inner frame to the outer frame.
+    fn mul(self, rhs: Point2<f64>) -> Self::Output {
+        self.transform_point(rhs)
+    }
+}
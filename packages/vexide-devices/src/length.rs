@@ -0,0 +1,150 @@
+//! Standard return type for sensors measuring distance
+
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A distance measured by a sensor.
+///
+/// Lengths can be created from and converted to any common unit, which avoids mixing up
+/// millimeter sensor readings with field measurements in inches.
+///
+/// # Examples
+///
+/// ```
+/// use vexide_devices::length::Length;
+///
+/// let distance = Length::from_inches(2.0);
+/// assert_eq!(distance.as_mm(), 50.8);
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Length(f64);
+
+impl Length {
+    /// The number of millimeters in an inch.
+    const MM_PER_INCH: f64 = 25.4;
+
+    /// A length of zero.
+    pub const ZERO: Self = Self(0.0);
+
+    /// Creates a length from a specified number of millimeters.
+    pub const fn from_mm(mm: f64) -> Self {
+        Self(mm)
+    }
+
+    /// Creates a length from a specified number of centimeters.
+    pub fn from_cm(cm: f64) -> Self {
+        Self(cm * 10.0)
+    }
+
+    /// Creates a length from a specified number of meters.
+    pub fn from_meters(meters: f64) -> Self {
+        Self(meters * 1000.0)
+    }
+
+    /// Creates a length from a specified number of inches.
+    pub fn from_inches(inches: f64) -> Self {
+        Self(inches * Self::MM_PER_INCH)
+    }
+
+    /// Creates a length from a specified number of feet.
+    pub fn from_feet(feet: f64) -> Self {
+        Self::from_inches(feet * 12.0)
+    }
+
+    /// Returns the number of millimeters in this length.
+    pub const fn as_mm(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns the number of centimeters in this length.
+    pub fn as_cm(&self) -> f64 {
+        self.0 / 10.0
+    }
+
+    /// Returns the number of meters in this length.
+    pub fn as_meters(&self) -> f64 {
+        self.0 / 1000.0
+    }
+
+    /// Returns the number of inches in this length.
+    pub fn as_inches(&self) -> f64 {
+        self.0 / Self::MM_PER_INCH
+    }
+
+    /// Returns the number of feet in this length.
+    pub fn as_feet(&self) -> f64 {
+        self.as_inches() / 12.0
+    }
+}
+
+impl Add<Length> for Length {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub<Length> for Length {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Mul<f64> for Length {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl Div<f64> for Length {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+
+impl Div<Length> for Length {
+    type Output = f64;
+
+    /// Returns the ratio between two lengths.
+    fn div(self, rhs: Self) -> Self::Output {
+        self.0 / rhs.0
+    }
+}
+
+impl AddAssign<Length> for Length {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign<Length> for Length {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl MulAssign<f64> for Length {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.0 *= rhs;
+    }
+}
+
+impl DivAssign<f64> for Length {
+    fn div_assign(&mut self, rhs: f64) {
+        self.0 /= rhs;
+    }
+}
+
+impl Neg for Length {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
@@ -8,9 +8,13 @@
 //!
 //! - [`smart`] contains abstractions and types for smart port connected devices.
 //! - [`adi`] contains abstractions for three wire ADI connected devices.
+//! - [`calibrate`] provides a common interface for devices that need calibration.
+//! - [`sensor`] provides a common interface for reading from sensors.
 //! - [`battery`] provides functions for getting information about the currently connected
 //!   battery.
 //! - [`controller`] provides types for interacting with the V5 controller.
+//! - [`control`] provides control theory utilities such as motion profiles.
+//! - [`shared`] provides a way to share devices between multiple tasks.
 
 #![no_std]
 
@@ -20,12 +24,18 @@ pub mod adi;
 pub mod smart;
 
 pub mod battery;
+pub mod calibrate;
 pub mod color;
+pub mod control;
 pub mod controller;
 pub mod display;
 pub mod geometry;
+pub mod length;
 pub mod peripherals;
 pub mod position;
+mod registry;
+pub mod sensor;
+pub mod shared;
 pub mod usd;
 
 use snafu::Snafu;
@@ -28,7 +28,8 @@ use crate::{
     adi::AdiPort,
     controller::{Controller, ControllerId},
     display::Display,
-    smart::SmartPort,
+    registry,
+    smart::{Port, SmartPort},
 };
 
 static PERIPHERALS_TAKEN: AtomicBool = AtomicBool::new(false);
@@ -45,6 +46,14 @@ static PERIPHERALS_TAKEN: AtomicBool = AtomicBool::new(false);
 ///
 /// If you need to store a peripherals struct for use in multiple functions, use [`DynamicPeripherals`] instead.
 /// This struct is always preferred over [`DynamicPeripherals`] when possible.
+///
+/// # Shutdown
+///
+/// Creating peripherals registers an [exit hook](vexide_core::program::on_exit) that shuts down
+/// every device created during the program when it [exits](vexide_core::program::exit). After
+/// motors are stopped and the program's own exit hooks have run, ADI outputs are neutralized
+/// (digital outputs driven low, PWM outputs zeroed, and LED strips turned off), every configured
+/// ADI output port is reconfigured as an input, and every port is released.
 #[derive(Debug)]
 pub struct Peripherals {
     /// Brain display
@@ -117,9 +126,23 @@ pub struct Peripherals {
     pub adi_h: AdiPort,
 }
 
+/// Creates a smart port from a port number that is known to be valid.
+///
+/// # Safety
+///
+/// The caller must ensure that only one [`SmartPort`] is created for each port.
+unsafe fn smart_port(number: u8) -> SmartPort {
+    match Port::new(number) {
+        Some(port) => unsafe { SmartPort::new(port) },
+        None => panic!("Invalid smart port number."),
+    }
+}
+
 impl Peripherals {
     // SAFETY: caller must ensure that the SmartPorts and AdiPorts created are unique
     unsafe fn new() -> Self {
+        registry::register_shutdown();
+
         // SAFETY: caller must ensure that this function is only called once
         unsafe {
             Self {
@@ -128,36 +151,36 @@ impl Peripherals {
                 primary_controller: Controller::new(ControllerId::Primary),
                 partner_controller: Controller::new(ControllerId::Partner),
 
-                port_1: SmartPort::new(1),
-                port_2: SmartPort::new(2),
-                port_3: SmartPort::new(3),
-                port_4: SmartPort::new(4),
-                port_5: SmartPort::new(5),
-                port_6: SmartPort::new(6),
-                port_7: SmartPort::new(7),
-                port_8: SmartPort::new(8),
-                port_9: SmartPort::new(9),
-                port_10: SmartPort::new(10),
-                port_11: SmartPort::new(11),
-                port_12: SmartPort::new(12),
-                port_13: SmartPort::new(13),
-                port_14: SmartPort::new(14),
-                port_15: SmartPort::new(15),
-                port_16: SmartPort::new(16),
-                port_17: SmartPort::new(17),
-                port_18: SmartPort::new(18),
-                port_19: SmartPort::new(19),
-                port_20: SmartPort::new(20),
-                port_21: SmartPort::new(21),
+                port_1: smart_port(1),
+                port_2: smart_port(2),
+                port_3: smart_port(3),
+                port_4: smart_port(4),
+                port_5: smart_port(5),
+                port_6: smart_port(6),
+                port_7: smart_port(7),
+                port_8: smart_port(8),
+                port_9: smart_port(9),
+                port_10: smart_port(10),
+                port_11: smart_port(11),
+                port_12: smart_port(12),
+                port_13: smart_port(13),
+                port_14: smart_port(14),
+                port_15: smart_port(15),
+                port_16: smart_port(16),
+                port_17: smart_port(17),
+                port_18: smart_port(18),
+                port_19: smart_port(19),
+                port_20: smart_port(20),
+                port_21: smart_port(21),
 
-                adi_a: AdiPort::new(1, None),
-                adi_b: AdiPort::new(2, None),
-                adi_c: AdiPort::new(3, None),
-                adi_d: AdiPort::new(4, None),
-                adi_e: AdiPort::new(5, None),
-                adi_f: AdiPort::new(6, None),
-                adi_g: AdiPort::new(7, None),
-                adi_h: AdiPort::new(8, None),
+                adi_a: AdiPort::new_onboard(1),
+                adi_b: AdiPort::new_onboard(2),
+                adi_c: AdiPort::new_onboard(3),
+                adi_d: AdiPort::new_onboard(4),
+                adi_e: AdiPort::new_onboard(5),
+                adi_f: AdiPort::new_onboard(6),
+                adi_g: AdiPort::new_onboard(7),
+                adi_h: AdiPort::new_onboard(8),
             }
         }
     }
@@ -221,12 +244,13 @@ impl DynamicPeripherals {
     /// This function panics if the provided port is outside the range 1-21.
     /// Ports outside of this range are invalid and cannot be created.
     pub fn take_smart_port(&mut self, port_number: u8) -> Option<SmartPort> {
-        let port_index = port_number as usize - 1;
+        let port = Port::new(port_number).expect("Invalid smart port number.");
+        let port_index = port.index() as usize;
         if self.smart_ports[port_index] {
             return None;
         };
         self.smart_ports[port_index] = true;
-        Some(unsafe { SmartPort::new(port_number) })
+        Some(unsafe { SmartPort::new(port) })
     }
 
     /// Creates an [`AdiPort`] only if one has not been created on the given slot before.
@@ -240,8 +264,8 @@ impl DynamicPeripherals {
         if self.adi_slots[port_number] {
             return None;
         }
-        self.smart_ports[port_number] = true;
-        Some(unsafe { AdiPort::new(port_number as u8 + 1, None) })
+        self.adi_slots[port_number] = true;
+        Some(unsafe { AdiPort::new_onboard(port_number as u8 + 1) })
     }
 
     /// Creates a [`Display`] only if one has not been created before.
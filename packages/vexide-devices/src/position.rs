@@ -2,7 +2,7 @@
 
 use core::{
     f64::consts::TAU,
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
 };
 
 /// A opaque fixed-point raw angular position reading from a sensor.
@@ -122,3 +122,62 @@ impl Neg for Position {
         Self(-self.0)
     }
 }
+
+/// Wraps an angle into the range `0..revolution`, where `revolution` is the size of a full turn in
+/// the angle's units.
+///
+/// This is shared by sensors that report a bounded angle, so that angles just below zero wrap to
+/// the end of the range and angles of exactly one revolution wrap back to zero.
+pub(crate) fn wrap_angle<T>(angle: T, revolution: T) -> T
+where
+    T: Add<Output = T> + Rem<Output = T> + Copy,
+{
+    // `%` keeps the sign of `angle`, so shift negative remainders back into range before wrapping
+    // again.
+    ((angle % revolution) + revolution) % revolution
+}
+
+/// The direction that a device rotates in, or measures rotation in.
+///
+/// Devices that can be reversed, such as [motors](crate::smart::motor::Motor),
+/// [rotation sensors](crate::smart::rotation::RotationSensor), and
+/// [encoders](crate::adi::encoder::AdiEncoder), take a [`Direction`] when they are created. A
+/// reversed device negates every reading and command, so the rest of the program doesn't need to
+/// flip any signs.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Direction {
+    /// The device rotates in the forward direction.
+    Forward,
+
+    /// The device rotates in the reverse direction.
+    Reverse,
+}
+
+impl Direction {
+    /// Returns `true` if the direction is [`Forward`](Direction::Forward).
+    pub const fn is_forward(&self) -> bool {
+        match self {
+            Self::Forward => true,
+            Self::Reverse => false,
+        }
+    }
+
+    /// Returns `true` if the direction is [`Reverse`](Direction::Reverse).
+    pub const fn is_reverse(&self) -> bool {
+        match self {
+            Self::Forward => false,
+            Self::Reverse => true,
+        }
+    }
+}
+
+impl core::ops::Not for Direction {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        match self {
+            Self::Forward => Self::Reverse,
+            Self::Reverse => Self::Forward,
+        }
+    }
+}
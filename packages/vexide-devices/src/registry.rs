@@ -0,0 +1,159 @@
+//! Tracking of constructed devices for a coordinated shutdown.
+//!
+//! Exiting the program with [`program::exit`] doesn't unwind, so devices owned by the program are
+//! never dropped and their own cleanup never runs. Instead, the devices that have been constructed
+//! are recorded here as they are created: smart ports are tracked through their claims (see
+//! [`SmartPort`](crate::smart::SmartPort)), and each ADI port is recorded along with the kind of
+//! device it was configured for.
+//!
+//! [`shut_down`] is registered as an [exit hook](program::on_exit) the first time
+//! [`Peripherals`](crate::peripherals::Peripherals) are created. Since that happens before `main`
+//! runs, it is the first hook registered and therefore the last to run. The full shutdown order is:
+//!
+//! 1. [`program::exit`] stops every smart motor and legacy ADI motor.
+//! 2. Exit hooks registered by the program run, most recently registered first, while every device
+//!    is still usable.
+//! 3. Every recorded ADI output is neutralized: digital outputs are driven low, PWM and motor outputs
+//!    are set to zero, and addressable LED strips are turned off.
+//! 4. Every recorded ADI port is released by reconfiguring it as an input, so that it stops driving
+//!    whatever is attached to it, and then every smart port claim is released. Both happen in port
+//!    order.
+//! 5. [`program::exit`] flushes serial output and ends the program.
+
+use core::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+use vex_sdk::{
+    vexDeviceAdiAddrLedSet, vexDeviceAdiPortConfigSet, vexDeviceAdiValueSet, vexDeviceGetByIndex,
+    V5_AdiPortConfiguration, V5_MAX_DEVICE_PORTS,
+};
+use vexide_core::program;
+
+use crate::{
+    adi::{addrled::AdiAddrLed, AdiDeviceType, AdiPort},
+    smart,
+};
+
+/// What kind of device an ADI port was last configured for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum AdiRole {
+    /// No device has been created on the port.
+    None = 0,
+
+    /// A sensor, which doesn't need to be neutralized.
+    Input,
+
+    /// A digital output, such as a solenoid.
+    DigitalOutput,
+
+    /// A PWM output or legacy motor.
+    PwmOutput,
+
+    /// A servo, which would move to its center position if its output were set to zero.
+    Servo,
+
+    /// An addressable LED strip.
+    AddrLed,
+}
+
+impl AdiRole {
+    const fn from_raw(raw: u8) -> Self {
+        match raw {
+            1 => Self::Input,
+            2 => Self::DigitalOutput,
+            3 => Self::PwmOutput,
+            4 => Self::Servo,
+            5 => Self::AddrLed,
+            _ => Self::None,
+        }
+    }
+}
+
+impl From<AdiDeviceType> for AdiRole {
+    fn from(value: AdiDeviceType) -> Self {
+        match value {
+            AdiDeviceType::Undefined => Self::None,
+            AdiDeviceType::DigitalOut => Self::DigitalOutput,
+            AdiDeviceType::PwmOut | AdiDeviceType::Motor | AdiDeviceType::MotorSlew => {
+                Self::PwmOutput
+            }
+            AdiDeviceType::Servo => Self::Servo,
+            _ => Self::Input,
+        }
+    }
+}
+
+#[allow(clippy::declare_interior_mutable_const)]
+const ADI_ROLE_INIT: AtomicU8 = AtomicU8::new(AdiRole::None as u8);
+#[allow(clippy::declare_interior_mutable_const)]
+const ADI_ROLES_INIT: [AtomicU8; 8] = [ADI_ROLE_INIT; 8];
+
+/// The [`AdiRole`] of every ADI port, indexed by the smart port index of the device that owns the
+/// port (an expander, or the brain's internal ADI device) and then by the port's index.
+static ADI_ROLES: [[AtomicU8; 8]; V5_MAX_DEVICE_PORTS] = [ADI_ROLES_INIT; V5_MAX_DEVICE_PORTS];
+
+/// Set once [`shut_down`] has been registered as an exit hook.
+static SHUTDOWN_REGISTERED: AtomicBool = AtomicBool::new(false);
+
+/// Registers [`shut_down`] as an exit hook if it hasn't been registered already.
+pub(crate) fn register_shutdown() {
+    if !SHUTDOWN_REGISTERED.swap(true, Ordering::AcqRel) {
+        program::on_exit(shut_down);
+    }
+}
+
+/// Records that a device with the given role has been created on an ADI port.
+pub(crate) fn register_adi(port: &AdiPort, role: AdiRole) {
+    ADI_ROLES[port.expander_index() as usize][port.index() as usize]
+        .store(role as u8, Ordering::Release);
+}
+
+/// Neutralizes every recorded ADI output, then releases every recorded port.
+///
+/// See the [module-level documentation](self) for where this fits into the shutdown order.
+fn shut_down() {
+    for (expander_index, roles) in ADI_ROLES.iter().enumerate() {
+        let device = unsafe { vexDeviceGetByIndex(expander_index as u32) };
+
+        for (index, role) in roles.iter().enumerate() {
+            match AdiRole::from_raw(role.load(Ordering::Acquire)) {
+                AdiRole::DigitalOutput | AdiRole::PwmOutput => unsafe {
+                    vexDeviceAdiValueSet(device, index as u32, 0);
+                },
+                AdiRole::AddrLed => {
+                    let mut off = [0; AdiAddrLed::MAX_LENGTH];
+                    unsafe {
+                        vexDeviceAdiAddrLedSet(
+                            device,
+                            index as u32,
+                            off.as_mut_ptr(),
+                            0,
+                            off.len() as u32,
+                            0,
+                        );
+                    }
+                }
+                AdiRole::None | AdiRole::Input | AdiRole::Servo => {}
+            }
+        }
+    }
+
+    for (expander_index, roles) in ADI_ROLES.iter().enumerate() {
+        let device = unsafe { vexDeviceGetByIndex(expander_index as u32) };
+
+        for (index, role) in roles.iter().enumerate() {
+            match AdiRole::from_raw(role.swap(AdiRole::None as u8, Ordering::AcqRel)) {
+                AdiRole::None | AdiRole::Input => {}
+                _ => unsafe {
+                    vexDeviceAdiPortConfigSet(
+                        device,
+                        index as u32,
+                        V5_AdiPortConfiguration::kAdiPortTypeAnalogIn,
+                    );
+                },
+            }
+        }
+    }
+
+    smart::release_all_ports();
+}
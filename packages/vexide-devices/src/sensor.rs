@@ -0,0 +1,51 @@
+//! Uniform access to sensor readings.
+//!
+//! Every sensor has its own methods for reading its data, which makes it hard to write code (such as
+//! loggers or sensor fusion) that works with many kinds of sensors. The [`Sensor`] trait provides a
+//! single [`read`](Sensor::read) method that returns each sensor's primary reading, so that
+//! sensors can be polled generically.
+//!
+//! The following devices implement [`Sensor`]:
+//!
+//! - [`InertialSensor`](crate::smart::imu::InertialSensor), reading its heading in degrees.
+//! - [`DistanceSensor`](crate::smart::distance::DistanceSensor), reading the detected object.
+//! - [`RotationSensor`](crate::smart::rotation::RotationSensor), reading its position.
+//! - [`AdiEncoder`](crate::adi::encoder::AdiEncoder), reading its position.
+//! - [`AdiGyro`](crate::adi::gyro::AdiGyro), reading its position.
+//! - [`AdiAnalogIn`](crate::adi::analog::AdiAnalogIn), reading its analog value.
+//! - [`AdiPotentiometer`](crate::adi::potentiometer::AdiPotentiometer), reading its angle in
+//!   degrees.
+//! - [`AdiDigitalIn`](crate::adi::digital::AdiDigitalIn), reading whether its signal is high.
+//!
+//! # Example
+//!
+//! Sensors with the same reading and error types can be stored together as trait objects:
+//!
+//! ```no_run
+//! let sensors: Vec<Box<dyn Sensor<Reading = Position, Error = PortError>>> = vec![
+//!     Box::new(RotationSensor::new(peripherals.port_1, Direction::Forward)),
+//!     Box::new(AdiGyro::new(peripherals.adi_a, 1.0)),
+//! ];
+//!
+//! loop {
+//!     for sensor in &sensors {
+//!         println!("{:?}", sensor.read());
+//!     }
+//!
+//!     sleep(Duration::from_millis(10)).await;
+//! }
+//! ```
+
+/// A device that produces readings.
+///
+/// See the [module-level documentation](self) for more information.
+pub trait Sensor {
+    /// The value read from the sensor.
+    type Reading;
+
+    /// The error that can occur while reading from the sensor.
+    type Error;
+
+    /// Reads the sensor's current value.
+    fn read(&self) -> Result<Self::Reading, Self::Error>;
+}
@@ -0,0 +1,84 @@
+//! Sharing devices between tasks.
+//!
+//! Device types in vexide can be sent between tasks, but they aren't [`Clone`], since each device
+//! owns the port it's plugged into. Tasks spawned onto the executor must also be `'static`, so they
+//! can't borrow a device owned by another task.
+//!
+//! [`SharedDevice`] solves both problems by placing a device behind a reference-counted async
+//! [`Mutex`]. Each clone of a [`SharedDevice`] refers to the same device, and tasks take turns
+//! accessing it by locking the mutex.
+//!
+//! # Example
+//!
+//! ```no_run
+//! let imu = SharedDevice::new(InertialSensor::new(peripherals.port_1));
+//!
+//! // A task that logs the IMU's heading.
+//! spawn({
+//!     let imu = imu.clone();
+//!     async move {
+//!         loop {
+//!             println!("{:?}", imu.lock().await.heading());
+//!             sleep(Duration::from_millis(100)).await;
+//!         }
+//!     }
+//! })
+//! .detach();
+//!
+//! // The main task can still use the IMU too.
+//! imu.lock().await.calibrate().await?;
+//! ```
+//!
+//! Since vexide's executor is cooperative, a task holding the lock won't be interrupted by other
+//! tasks. Holding a lock across an `.await` will block other tasks from accessing the device until
+//! the lock is released, so keep lock guards as short-lived as possible.
+
+use alloc::sync::Arc;
+
+use vexide_core::sync::{Mutex, MutexGuard, MutexLockFuture};
+
+/// A device that can be shared between multiple tasks.
+///
+/// See the [module-level documentation](self) for more information.
+#[derive(Debug)]
+pub struct SharedDevice<T> {
+    inner: Arc<Mutex<T>>,
+}
+
+impl<T> SharedDevice<T> {
+    /// Wraps a device so that it can be shared between tasks.
+    pub fn new(device: T) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(device)),
+        }
+    }
+
+    /// Returns a future that resolves with exclusive access to the device once no other task is
+    /// using it.
+    pub fn lock(&self) -> MutexLockFuture<'_, T> {
+        self.inner.lock()
+    }
+
+    /// Attempts to access the device without waiting, returning `None` if another task is currently
+    /// using it.
+    pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
+        self.inner.try_lock()
+    }
+
+    /// Returns the device if this is the only remaining handle to it.
+    ///
+    /// If other clones of this handle still exist, the handle is returned back as an error.
+    pub fn try_into_inner(self) -> Result<T, Self> {
+        Arc::try_unwrap(self.inner)
+            .map(Mutex::into_inner)
+            .map_err(|inner| Self { inner })
+    }
+}
+
+impl<T> Clone for SharedDevice<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
@@ -1,5 +1,11 @@
 //! Distance sensor device.
 
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
 use snafu::Snafu;
 use vex_sdk::{
     vexDeviceDistanceConfidenceGet, vexDeviceDistanceDistanceGet, vexDeviceDistanceObjectSizeGet,
@@ -7,7 +13,7 @@ use vex_sdk::{
 };
 
 use super::{SmartDevice, SmartDeviceType, SmartPort};
-use crate::PortError;
+use crate::{length::Length, sensor::Sensor, PortError};
 
 /// A physical distance sensor plugged into a port.
 /// Distance sensors can only keep track of one object at a time.
@@ -65,6 +71,45 @@ impl DistanceSensor {
         }
     }
 
+    /// Returns the distance to the detected object, or `None` if no object could be found.
+    ///
+    /// This is the same reading as [`DistanceObject::distance`], but as a [`Length`] that can be
+    /// converted to any unit.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// if let Some(distance) = distance_sensor.distance()? {
+    ///     println!("Wall is {:.1} inches away", distance.as_inches());
+    /// }
+    /// ```
+    pub fn distance(&self) -> Result<Option<Length>, DistanceError> {
+        Ok(self
+            .object()?
+            .map(|object| Length::from_mm(object.distance as f64)))
+    }
+
+    /// Returns a future that resolves once the sensor detects an object closer than
+    /// `within_mm` millimeters, yielding the detected object.
+    ///
+    /// If an object is already within range, the future resolves the first time it is polled.
+    /// The future keeps waiting while the sensor is still initializing, but resolves with an
+    /// error if the sensor is disconnected or reports a bad status code.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// // Wait for a game element to enter the intake before running the conveyor.
+    /// let object = distance_sensor.wait_for_object(50).await?;
+    /// println!("Object detected {}mm away", object.distance);
+    /// ```
+    pub const fn wait_for_object(&self, within_mm: u32) -> ObjectDetectedFuture<'_> {
+        ObjectDetectedFuture {
+            sensor: self,
+            within_mm,
+        }
+    }
+
     /// Gets the status code of the distance sensor
     pub fn status(&self) -> Result<u32, DistanceError> {
         self.validate_port()?;
@@ -73,6 +118,16 @@ impl DistanceSensor {
     }
 }
 
+impl Sensor for DistanceSensor {
+    type Reading = Option<DistanceObject>;
+    type Error = DistanceError;
+
+    /// Reads the [object](DistanceSensor::object) currently detected by the sensor, if any.
+    fn read(&self) -> Result<Self::Reading, Self::Error> {
+        self.object()
+    }
+}
+
 impl SmartDevice for DistanceSensor {
     fn port_number(&self) -> u8 {
         self.port.number()
@@ -88,6 +143,31 @@ impl From<DistanceSensor> for SmartPort {
     }
 }
 
+/// A future that resolves once a [`DistanceSensor`] detects an object within a given range.
+///
+/// See [`DistanceSensor::wait_for_object`] for more information.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ObjectDetectedFuture<'a> {
+    sensor: &'a DistanceSensor,
+    within_mm: u32,
+}
+
+impl Future for ObjectDetectedFuture<'_> {
+    type Output = Result<DistanceObject, DistanceError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.sensor.object() {
+            Ok(Some(object)) if object.distance < self.within_mm => Poll::Ready(Ok(object)),
+            Ok(_) | Err(DistanceError::StillInitializing) => {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+            Err(err) => Poll::Ready(Err(err)),
+        }
+    }
+}
+
 /// Readings from a phyiscal object detected by a Distance Sensor.
 #[derive(Default, Debug, Clone, PartialEq, PartialOrd)]
 pub struct DistanceObject {
@@ -19,7 +19,10 @@ use vexide_core::time::Instant;
 
 use super::{validate_port, SmartDevice, SmartDeviceType, SmartPort};
 use crate::{
+    calibrate::Calibrate,
     geometry::{EulerAngles, Quaternion, Vector3},
+    position::wrap_angle,
+    sensor::Sensor,
     PortError,
 };
 
@@ -30,6 +33,7 @@ pub struct InertialSensor {
     device: V5_DeviceT,
     rotation_offset: f64,
     heading_offset: f64,
+    data_interval: Duration,
 }
 
 // SAFETY: Required because we store a raw pointer to the device handle to avoid it getting from the
@@ -47,6 +51,9 @@ impl InertialSensor {
     /// The minimum data rate that you can set an IMU to.
     pub const MIN_DATA_INTERVAL: Duration = Duration::from_millis(5);
 
+    /// The data rate that an IMU uses by default.
+    pub const DEFAULT_DATA_INTERVAL: Duration = Duration::from_millis(10);
+
     /// The maximum value that can be returned by [`Self::heading`].
     pub const MAX_HEADING: f64 = 360.0;
 
@@ -57,6 +64,7 @@ impl InertialSensor {
             port,
             rotation_offset: 0.0,
             heading_offset: 0.0,
+            data_interval: Self::DEFAULT_DATA_INTERVAL,
         }
     }
 
@@ -121,10 +129,10 @@ impl InertialSensor {
     /// represented with negative ones.
     pub fn heading(&self) -> Result<f64, InertialError> {
         self.validate()?;
-        Ok(
-            (unsafe { vexDeviceImuDegreesGet(self.device) } - self.heading_offset)
-                % Self::MAX_HEADING,
-        )
+        Ok(wrap_angle(
+            unsafe { vexDeviceImuDegreesGet(self.device) } - self.heading_offset,
+            Self::MAX_HEADING,
+        ))
     }
 
     /// Get a quaternion representing the Inertial Sensor’s orientation.
@@ -231,7 +239,9 @@ impl InertialSensor {
 
     /// Sets the computation speed of the IMU.
     ///
-    /// This duration should be above [`Self::MIN_DATA_INTERVAL`] (5 milliseconds).
+    /// This duration should be above [`Self::MIN_DATA_INTERVAL`] (5 milliseconds), and is rounded
+    /// down to a multiple of 5 milliseconds. Faster data rates result in smoother heading readings at
+    /// the cost of more traffic between the sensor and the brain.
     pub fn set_data_rate(&mut self, data_rate: Duration) -> Result<(), InertialError> {
         self.validate()?;
 
@@ -242,8 +252,37 @@ impl InertialSensor {
 
         unsafe { vexDeviceImuDataRateSet(self.device, time_ms) }
 
+        self.data_interval = Duration::from_millis(time_ms as u64);
+
         Ok(())
     }
+
+    /// Returns the computation speed of the IMU last set by [`InertialSensor::set_data_rate`].
+    ///
+    /// VEXos doesn't provide a way to read the data rate back from the sensor, so this is tracked by
+    /// the [`InertialSensor`] itself and starts at [`Self::DEFAULT_DATA_INTERVAL`].
+    pub const fn data_rate(&self) -> Duration {
+        self.data_interval
+    }
+}
+
+impl Calibrate for InertialSensor {
+    type Error = InertialError;
+    type Future<'a> = InertialCalibrateFuture;
+
+    fn calibrate(&mut self) -> Self::Future<'_> {
+        InertialSensor::calibrate(self)
+    }
+}
+
+impl Sensor for InertialSensor {
+    type Reading = f64;
+    type Error = InertialError;
+
+    /// Reads the sensor's [heading](InertialSensor::heading) in degrees.
+    fn read(&self) -> Result<Self::Reading, Self::Error> {
+        self.heading()
+    }
 }
 
 impl SmartDevice for InertialSensor {
@@ -23,12 +23,20 @@ pub mod gps;
 pub mod imu;
 pub mod link;
 pub mod motor;
+pub mod motor_group;
 pub mod optical;
 pub mod rotation;
 pub mod serial;
 pub mod vision;
 
-use core::fmt;
+use core::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicU32, AtomicU64, AtomicU8, Ordering},
+    task::{Context, Poll},
+    time::Duration,
+};
 
 pub use distance::DistanceSensor;
 pub use expander::AdiExpander;
@@ -36,19 +44,34 @@ pub use gps::GpsSensor;
 pub use imu::InertialSensor;
 pub use link::RadioLink;
 pub use motor::Motor;
+pub use motor_group::MotorGroup;
 pub use optical::OpticalSensor;
 pub use rotation::RotationSensor;
 pub use serial::SerialPort;
+use snafu::Snafu;
 use vex_sdk::{
     vexDeviceGetByIndex, vexDeviceGetStatus, vexDeviceGetTimestamp, V5_DeviceT, V5_DeviceType,
     V5_MAX_DEVICE_PORTS,
 };
+use vexide_async::time::{sleep, Sleep};
+#[doc(inline)]
+pub use vexide_core::program::stop_all_motors;
+use vexide_core::{
+    sync::Mutex,
+    time::{self, Instant},
+};
 pub use vision::VisionSensor;
 
 use crate::PortError;
 
 /// Defines common functionality shared by all smart port devices.
 pub trait SmartDevice {
+    /// How often the device is expected to send the brain new data.
+    ///
+    /// This is used by [`SmartDevice::next_update`] to decide how long to wait before checking for
+    /// new data again.
+    const UPDATE_INTERVAL: Duration = Duration::from_millis(10);
+
     /// Get the port number of the [`SmartPort`] this device is registered on.
     ///
     /// Ports are numbered starting from 1.
@@ -56,7 +79,7 @@ pub trait SmartDevice {
     /// # Examples
     ///
     /// ```
-    /// let sensor = InertialSensor::new(peripherals.port_1)?;
+    /// let sensor = InertialSensor::new(peripherals.port_1);
     /// assert_eq!(sensor.port_number(), 1);
     /// ```
     fn port_number(&self) -> u8;
@@ -66,7 +89,7 @@ pub trait SmartDevice {
     /// # Examples
     ///
     /// ```
-    /// let sensor = InertialSensor::new(peripherals.port_1)?;
+    /// let sensor = InertialSensor::new(peripherals.port_1);
     /// assert_eq!(sensor.device_type(), SmartDeviceType::Imu);
     /// ```
     fn device_type(&self) -> SmartDeviceType;
@@ -77,7 +100,7 @@ pub trait SmartDevice {
     /// # Examples
     ///
     /// ```
-    /// let sensor = InertialSensor::new(peripherals.port_1)?;
+    /// let sensor = InertialSensor::new(peripherals.port_1);
     ///
     /// if sensor.port_connected() {
     ///     println!("IMU is connected!");
@@ -101,23 +124,243 @@ pub trait SmartDevice {
         }))
     }
 
+    /// Returns the time at which the brain first observed the device's latest data.
+    ///
+    /// [`SmartDeviceTimestamp`]s come from the device's own clock and can't be compared with the
+    /// brain's time, so this instead records the [`Instant`] at which the device's timestamp was
+    /// first seen to change. The returned instant can be used to reject stale readings, such as
+    /// when a device stops sending data during a brownout.
+    ///
+    /// Because the change is only detected when this function is called, its precision depends on
+    /// how often it's called; calling it once per control loop iteration is usually sufficient.
+    /// This tracking is shared between all devices on the same port. The first call for a port
+    /// returns the current time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let sensor = InertialSensor::new(peripherals.port_1);
+    ///
+    /// if sensor.last_update()?.elapsed() > Duration::from_millis(50) {
+    ///     println!("IMU data is stale!");
+    /// }
+    /// ```
+    fn last_update(&self) -> Result<Instant, PortError> {
+        self.validate_port()?;
+
+        let now = time::micros();
+        let last_seen = observe_timestamp(self.port_number(), self.timestamp()?);
+
+        Ok(Instant::now()
+            .checked_sub(Duration::from_micros(now.saturating_sub(last_seen)))
+            .unwrap_or_else(Instant::now))
+    }
+
     /// Verify that the device type is currently plugged into this port, returning an appropriate
     /// [`PortError`] if not available.
     fn validate_port(&self) -> Result<(), PortError> {
         validate_port(self.port_number(), self.device_type())
     }
+
+    /// Returns a future that resolves once the device has sent the brain new data.
+    ///
+    /// The future resolves with the [`SmartDeviceTimestamp`] of the new data, or an error if the
+    /// device is disconnected while waiting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let sensor = InertialSensor::new(peripherals.port_1);
+    ///
+    /// loop {
+    ///     sensor.next_update().await?;
+    ///     println!("{:?}", sensor.heading());
+    /// }
+    /// ```
+    fn next_update(&self) -> DataUpdateFuture {
+        DataUpdateFuture {
+            port_number: self.port_number(),
+            device_type: self.device_type(),
+            interval: Self::UPDATE_INTERVAL,
+            last_timestamp: None,
+            sleep: None,
+        }
+    }
+}
+
+#[allow(clippy::declare_interior_mutable_const)]
+const LAST_UPDATE_INIT_TIMESTAMP: AtomicU32 = AtomicU32::new(0);
+#[allow(clippy::declare_interior_mutable_const)]
+const LAST_UPDATE_INIT_MICROS: AtomicU64 = AtomicU64::new(0);
+
+/// The most recent [`SmartDeviceTimestamp`] observed on each port by [`SmartDevice::last_update`].
+static LAST_UPDATE_TIMESTAMPS: [AtomicU32; V5_MAX_DEVICE_PORTS] =
+    [LAST_UPDATE_INIT_TIMESTAMP; V5_MAX_DEVICE_PORTS];
+
+/// The brain time (in microseconds) at which each entry in [`LAST_UPDATE_TIMESTAMPS`] was first
+/// observed, or zero if the port hasn't been checked yet.
+static LAST_UPDATE_MICROS: [AtomicU64; V5_MAX_DEVICE_PORTS] =
+    [LAST_UPDATE_INIT_MICROS; V5_MAX_DEVICE_PORTS];
+
+/// How long [`DataUpdateFuture`] waits before checking again when a device's data is overdue.
+const OVERDUE_UPDATE_RETRY: Duration = Duration::from_millis(1);
+
+/// Records the time at which `timestamp` was first observed on a port, returning that time in
+/// microseconds.
+fn observe_timestamp(port_number: u8, timestamp: SmartDeviceTimestamp) -> u64 {
+    let index = (port_number - 1) as usize;
+    let now = time::micros();
+
+    let previous_timestamp = LAST_UPDATE_TIMESTAMPS[index].swap(timestamp.0, Ordering::AcqRel);
+    let last_seen = LAST_UPDATE_MICROS[index].load(Ordering::Acquire);
+
+    if last_seen == 0 || previous_timestamp != timestamp.0 {
+        LAST_UPDATE_MICROS[index].store(now, Ordering::Release);
+        return now;
+    }
+
+    last_seen
+}
+
+/// A future that resolves when a smart device reports new data.
+///
+/// VEXos does not provide notifications for when new device data arrives, so this future
+/// checks the device's timestamp and then sleeps until the device's next update is expected,
+/// based on when its latest data arrived and its [update interval](SmartDevice::UPDATE_INTERVAL).
+/// It resolves once the timestamp changes.
+///
+/// See [`SmartDevice::next_update`] for more information.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct DataUpdateFuture {
+    port_number: u8,
+    device_type: SmartDeviceType,
+    interval: Duration,
+    last_timestamp: Option<SmartDeviceTimestamp>,
+    sleep: Option<Sleep>,
+}
+
+impl Future for DataUpdateFuture {
+    type Output = Result<SmartDeviceTimestamp, PortError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            if let Some(sleep) = &mut self.sleep {
+                if Pin::new(sleep).poll(cx).is_pending() {
+                    return Poll::Pending;
+                }
+            }
+
+            if let Err(err) = validate_port(self.port_number, self.device_type) {
+                return Poll::Ready(Err(err));
+            }
+
+            let timestamp = SmartDeviceTimestamp(unsafe {
+                vexDeviceGetTimestamp(vexDeviceGetByIndex((self.port_number - 1) as u32))
+            });
+            let last_seen = observe_timestamp(self.port_number, timestamp);
+
+            match self.last_timestamp {
+                Some(last) if last != timestamp => return Poll::Ready(Ok(timestamp)),
+                _ => self.last_timestamp = Some(timestamp),
+            }
+
+            // Sleep until the device should have sent its next update, or retry shortly if that
+            // update is already late.
+            let expected = last_seen + self.interval.as_micros() as u64;
+            let now = time::micros();
+            self.sleep = Some(sleep(if expected > now {
+                Duration::from_micros(expected - now)
+            } else {
+                OVERDUE_UPDATE_RETRY
+            }));
+        }
+    }
+}
+
+/// How often (in microseconds) [`validate_port`] re-reads the connected device types from VEXos.
+static VALIDATION_INTERVAL_MICROS: AtomicU64 = AtomicU64::new(0);
+
+/// The most recently read device types on each port, along with the brain time (in microseconds)
+/// at which they were read.
+static DEVICE_STATUS_CACHE: Mutex<Option<([V5_DeviceType; V5_MAX_DEVICE_PORTS], u64)>> =
+    Mutex::new(None);
+
+/// Sets how long the result of a device type check can be reused before VEXos is asked again.
+///
+/// Every smart device method first checks that the expected device is plugged into its port, which
+/// requires reading the type of every connected device from VEXos. In loops that talk to many
+/// devices at a high rate, this adds up. Setting a nonzero interval lets these checks reuse the
+/// most recently read device types until they are older than `interval`.
+///
+/// A check that fails against cached data always re-reads the device types before returning an
+/// error, so a reconnected device is noticed immediately. However, a device that is unplugged may
+/// go unnoticed for up to `interval`, during which commands sent to it are silently dropped by
+/// VEXos. Intervals of a few tens of milliseconds are a reasonable tradeoff.
+///
+/// The default interval is [`Duration::ZERO`], meaning that every check reads fresh data.
+pub fn set_validation_interval(interval: Duration) {
+    VALIDATION_INTERVAL_MICROS.store(interval.as_micros() as u64, Ordering::Relaxed);
+}
+
+/// Returns how long the result of a device type check can be reused.
+///
+/// See [`set_validation_interval`] for more information.
+pub fn validation_interval() -> Duration {
+    Duration::from_micros(VALIDATION_INTERVAL_MICROS.load(Ordering::Relaxed))
+}
+
+/// Returns the type of device plugged into each port, reusing a cached read if it is newer than
+/// the [validation interval](set_validation_interval) and `refresh` is `false`.
+fn device_types(refresh: bool) -> [V5_DeviceType; V5_MAX_DEVICE_PORTS] {
+    let interval = VALIDATION_INTERVAL_MICROS.load(Ordering::Relaxed);
+    let now = time::micros();
+
+    // The cache is never held across an `.await`, so it should always be available. If it somehow
+    // isn't, read fresh data rather than blocking.
+    let mut cache = DEVICE_STATUS_CACHE.try_lock().filter(|_| interval != 0);
+
+    if let Some(Some((device_types, read_at))) = cache.as_deref() {
+        if !refresh && now.saturating_sub(*read_at) < interval {
+            return *device_types;
+        }
+    }
+
+    let mut device_types: [V5_DeviceType; V5_MAX_DEVICE_PORTS] = unsafe { core::mem::zeroed() };
+    unsafe {
+        vexDeviceGetStatus(device_types.as_mut_ptr());
+    }
+
+    if let Some(cache) = cache.as_deref_mut() {
+        *cache = Some((device_types, now));
+    }
+
+    device_types
 }
 
 /// Verify that the device type is currently plugged into this port.
 ///
 /// This function provides the internal implementations of [`SmartDevice::validate_port`], [`SmartPort::validate_type`],
 /// and [`AdiPort::validate_expander`].
+///
+/// Device types may be cached according to [`set_validation_interval`].
 pub(crate) fn validate_port(number: u8, device_type: SmartDeviceType) -> Result<(), PortError> {
-    let mut device_types: [V5_DeviceType; V5_MAX_DEVICE_PORTS] = unsafe { core::mem::zeroed() };
-    unsafe {
-        vexDeviceGetStatus(device_types.as_mut_ptr());
+    let result = check_port(&device_types(false), number, device_type);
+
+    if result.is_err() && VALIDATION_INTERVAL_MICROS.load(Ordering::Relaxed) != 0 {
+        // The cached device types may be stale, so make sure the error is real.
+        return check_port(&device_types(true), number, device_type);
     }
 
+    result
+}
+
+/// Checks the type of the device on port `number` against `device_type`.
+fn check_port(
+    device_types: &[V5_DeviceType; V5_MAX_DEVICE_PORTS],
+    number: u8,
+    device_type: SmartDeviceType,
+) -> Result<(), PortError> {
     let connected_type: SmartDeviceType = device_types[(number - 1) as usize].into();
 
     if connected_type == SmartDeviceType::None {
@@ -131,17 +374,128 @@ pub(crate) fn validate_port(number: u8, device_type: SmartDeviceType) -> Result<
     Ok(())
 }
 
+/// The number of a smart port on the V5 Brain.
+///
+/// Smart ports are labeled 1 through 21 on the brain, but are indexed from 0 through 20 internally.
+/// A [`Port`] is always in the range of valid port numbers, so it can be freely converted between
+/// the two without off-by-one errors.
+///
+/// A port's [`Display`](fmt::Display) implementation shows its number as labeled on the brain.
+///
+/// # Examples
+///
+/// ```
+/// let port = Port::new(1).unwrap();
+///
+/// assert_eq!(port.number(), 1);
+/// assert_eq!(port.index(), 0);
+/// assert!(Port::new(22).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Port {
+    index: u8,
+}
+
+impl Port {
+    /// The number of smart ports on the brain.
+    pub const COUNT: u8 = V5_MAX_DEVICE_PORTS as u8;
+
+    /// Creates a port from its number as labeled on the brain, returning `None` if it isn't in the
+    /// range `1..=21`.
+    pub const fn new(number: u8) -> Option<Self> {
+        if number >= 1 && number <= Self::COUNT {
+            Some(Self { index: number - 1 })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a port from its internal index, returning `None` if it isn't in the range `0..=20`.
+    pub const fn from_index(index: u8) -> Option<Self> {
+        if index < Self::COUNT {
+            Some(Self { index })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of the port as labeled on the brain, starting from 1.
+    pub const fn number(&self) -> u8 {
+        self.index + 1
+    }
+
+    /// Returns the internal index of the port, starting from 0.
+    pub const fn index(&self) -> u8 {
+        self.index
+    }
+}
+
+impl fmt::Display for Port {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl TryFrom<u8> for Port {
+    type Error = InvalidPortError;
+
+    /// Converts a port number as labeled on the brain into a [`Port`].
+    fn try_from(number: u8) -> Result<Self, Self::Error> {
+        Self::new(number).ok_or(InvalidPortError { number })
+    }
+}
+
+/// The error returned when a number isn't a valid smart [`Port`] number.
+#[derive(Debug, Snafu)]
+#[snafu(display("Port {number} does not exist. Smart ports are numbered from 1 to 21."))]
+pub struct InvalidPortError {
+    /// The invalid port number.
+    pub number: u8,
+}
+
+impl From<Port> for u8 {
+    fn from(port: Port) -> Self {
+        port.number()
+    }
+}
+
+/// The error returned when attempting to claim a smart port that is already in use.
+#[derive(Debug, Snafu)]
+#[snafu(display("Port {port} is already in use by another device."))]
+pub struct PortInUseError {
+    /// The port that is already in use.
+    pub port: Port,
+}
+
+#[allow(clippy::declare_interior_mutable_const)]
+const CLAIM_COUNT_INIT: AtomicU8 = AtomicU8::new(0);
+
+/// The number of [`SmartPort`] instances that currently exist for each port.
+///
+/// This is usually zero or one, but [`SmartPort::new`] can create extra instances of a port that is
+/// already in use. Counting them ensures that a port stays claimed until every instance is dropped.
+static CLAIM_COUNTS: [AtomicU8; V5_MAX_DEVICE_PORTS] = [CLAIM_COUNT_INIT; V5_MAX_DEVICE_PORTS];
+
 /// Represents a smart port on a V5 Brain
+///
+/// # Port Ownership
+///
+/// vexide keeps track of which ports currently have a [`SmartPort`], so that only one device can
+/// use each port at a time. A port is claimed when its [`SmartPort`] is created and released when it
+/// is dropped (including when the device that was constructed from it is dropped).
+/// [`SmartPort::try_new`] uses this to safely create a port, returning an error if it is already
+/// in use.
+///
+/// Ports created with the unsafe [`SmartPort::new`] (including those from
+/// [`Peripherals::steal`](crate::peripherals::Peripherals::steal)) are counted as well, so a port
+/// with several instances stays claimed until all of them are dropped.
 #[derive(Debug, Eq, PartialEq)]
 pub struct SmartPort {
-    /// The number of the port (port number).
-    ///
-    /// Ports are numbered starting from 1.
-    number: u8,
+    port: Port,
 }
 
 impl SmartPort {
-    /// Creates a new smart port on a specified index.
+    /// Creates a new smart port on a specified port.
     ///
     /// # Safety
     ///
@@ -153,13 +507,52 @@ impl SmartPort {
     /// # Examples
     ///
     /// ```
-    /// // Create a new smart port at index 1.
+    /// // Create a new smart port on port 1.
     /// // This is unsafe! You are responsible for ensuring that only one device registered on a
     /// // single port index.
-    /// let my_port = unsafe { SmartPort::new(1) };
+    /// let my_port = unsafe { SmartPort::new(Port::new(1).unwrap()) };
+    /// ```
+    pub unsafe fn new(port: Port) -> Self {
+        CLAIM_COUNTS[port.index() as usize].fetch_add(1, Ordering::AcqRel);
+        Self { port }
+    }
+
+    /// Creates a new smart port on a specified port, returning an error if that port is already in
+    /// use by another [`SmartPort`].
+    ///
+    /// This catches mistakes such as accidentally constructing two devices on the same port. Ports
+    /// handed out by [`Peripherals`](crate::peripherals::Peripherals) are in use for as long as they
+    /// (or the devices created from them) exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let port = Port::new(1).unwrap();
+    ///
+    /// let motor = Motor::new(SmartPort::try_new(port)?, Gearset::Green, Direction::Forward);
+    ///
+    /// // Port 1 is already being used by the motor.
+    /// assert!(SmartPort::try_new(port).is_err());
     /// ```
-    pub const unsafe fn new(number: u8) -> Self {
-        Self { number }
+    pub fn try_new(port: Port) -> Result<Self, PortInUseError> {
+        if CLAIM_COUNTS[port.index() as usize]
+            .compare_exchange(0, 1, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            return Err(PortInUseError { port });
+        }
+
+        Ok(Self { port })
+    }
+
+    /// Returns `true` if a [`SmartPort`] currently exists for the given port.
+    pub fn is_claimed(port: Port) -> bool {
+        CLAIM_COUNTS[port.index() as usize].load(Ordering::Acquire) != 0
+    }
+
+    /// Get the [`Port`] that this smart port is on.
+    pub const fn port(&self) -> Port {
+        self.port
     }
 
     /// Get the number of the port.
@@ -169,16 +562,16 @@ impl SmartPort {
     /// # Examples
     ///
     /// ```
-    /// let my_port = unsafe { SmartPort::new(1) };
+    /// let my_port = unsafe { SmartPort::new(Port::new(1).unwrap()) };
     ///
     /// assert_eq!(my_port.number(), 1);
     /// ```
     pub const fn number(&self) -> u8 {
-        self.number
+        self.port.number()
     }
 
     pub(crate) const fn index(&self) -> u32 {
-        (self.number - 1) as u32
+        self.port.index() as u32
     }
 
     /// Get the type of device currently connected to this port.
@@ -186,7 +579,7 @@ impl SmartPort {
     /// # Examples
     ///
     /// ```
-    /// let my_port = unsafe { SmartPort::new(1) };
+    /// let my_port = unsafe { SmartPort::new(Port::new(1).unwrap()) };
     ///
     /// println!("Type of device connected to port 1: {:?}", my_port.device_type());
     /// ```
@@ -211,6 +604,28 @@ impl SmartPort {
     }
 }
 
+impl Drop for SmartPort {
+    /// Releases the port so that it can be claimed again once no other instances of it exist.
+    fn drop(&mut self) {
+        // The count may already be zero if every claim was released when the program exited.
+        _ = CLAIM_COUNTS[self.port.index() as usize].fetch_update(
+            Ordering::AcqRel,
+            Ordering::Acquire,
+            |count| count.checked_sub(1),
+        );
+    }
+}
+
+/// Releases every port's claim in port order, regardless of how many instances of it exist.
+///
+/// This is called by the device registry while the program is exiting, since the devices holding
+/// the ports are never dropped.
+pub(crate) fn release_all_ports() {
+    for count in &CLAIM_COUNTS {
+        count.store(0, Ordering::Release);
+    }
+}
+
 /// Represents a possible type of device that can be registered on a [`SmartPort`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
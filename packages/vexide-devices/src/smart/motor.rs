@@ -1,28 +1,268 @@
 //! V5 Smart Motors
 
-use core::time::Duration;
+use core::{
+    future::Future,
+    mem::ManuallyDrop,
+    pin::Pin,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    task::{Context, Poll},
+    time::Duration,
+};
 
 use bitflags::bitflags;
 use snafu::Snafu;
 use vex_sdk::{
-    vexDeviceMotorAbsoluteTargetSet, vexDeviceMotorBrakeModeSet, vexDeviceMotorCurrentGet,
-    vexDeviceMotorCurrentLimitGet, vexDeviceMotorCurrentLimitSet, vexDeviceMotorEfficiencyGet,
-    vexDeviceMotorEncoderUnitsSet, vexDeviceMotorFaultsGet, vexDeviceMotorFlagsGet,
-    vexDeviceMotorGearingGet, vexDeviceMotorGearingSet, vexDeviceMotorPositionGet,
-    vexDeviceMotorPositionRawGet, vexDeviceMotorPositionReset, vexDeviceMotorPositionSet,
-    vexDeviceMotorPowerGet, vexDeviceMotorReverseFlagGet, vexDeviceMotorReverseFlagSet,
-    vexDeviceMotorTemperatureGet, vexDeviceMotorTorqueGet, vexDeviceMotorVelocityGet,
-    vexDeviceMotorVelocitySet, vexDeviceMotorVelocityUpdate, vexDeviceMotorVoltageGet,
-    vexDeviceMotorVoltageLimitGet, vexDeviceMotorVoltageLimitSet, vexDeviceMotorVoltageSet,
-    V5MotorBrakeMode, V5MotorGearset, V5_DeviceT,
+    vexDeviceGetByIndex, vexDeviceGetStatus, vexDeviceMotorAbsoluteTargetSet,
+    vexDeviceMotorBrakeModeSet, vexDeviceMotorCurrentGet, vexDeviceMotorCurrentLimitGet,
+    vexDeviceMotorCurrentLimitSet, vexDeviceMotorEfficiencyGet, vexDeviceMotorEncoderUnitsSet,
+    vexDeviceMotorFaultsGet, vexDeviceMotorFlagsGet, vexDeviceMotorGearingGet,
+    vexDeviceMotorGearingSet, vexDeviceMotorPositionGet, vexDeviceMotorPositionRawGet,
+    vexDeviceMotorPositionReset, vexDeviceMotorPositionSet, vexDeviceMotorPowerGet,
+    vexDeviceMotorReverseFlagGet, vexDeviceMotorReverseFlagSet, vexDeviceMotorTemperatureGet,
+    vexDeviceMotorTorqueGet, vexDeviceMotorVelocityGet, vexDeviceMotorVelocitySet,
+    vexDeviceMotorVelocityUpdate, vexDeviceMotorVoltageGet, vexDeviceMotorVoltageLimitGet,
+    vexDeviceMotorVoltageLimitSet, vexDeviceMotorVoltageSet, V5MotorBrakeMode, V5MotorGearset,
+    V5_DeviceT, V5_DeviceType, V5_MAX_DEVICE_PORTS,
 };
 #[cfg(feature = "dangerous_motor_tuning")]
 use vex_sdk::{vexDeviceMotorPositionPidSet, vexDeviceMotorVelocityPidSet, V5_DeviceMotorPid};
+use vexide_async::time::{sleep, Sleep};
+use vexide_core::{
+    competition::{self, CompetitionMode},
+    time::{self, Instant},
+};
+
+use super::{validate_port, SmartDevice, SmartDeviceTimestamp, SmartDeviceType, SmartPort};
+pub use crate::position::Direction;
+use crate::{battery, position::Position, PortError};
+
+static DISABLED_SAFETY: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables suppression of motor output while the robot is disabled.
+///
+/// When enabled, [`Motor::set_target`] (and the functions built on it, such as [`Motor::set_voltage`])
+/// will silently ignore any command other than braking while competition control reports the robot as
+/// [disabled](CompetitionMode::Disabled). This guards against stray commands moving the robot during the
+/// disabled period between autonomous and driver control.
+///
+/// This is disabled by default.
+pub fn set_disabled_safety(enabled: bool) {
+    DISABLED_SAFETY.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns `true` if motor output is suppressed while the robot is disabled.
+///
+/// See [`set_disabled_safety`] for more information.
+pub fn disabled_safety() -> bool {
+    DISABLED_SAFETY.load(Ordering::Relaxed)
+}
+
+#[allow(clippy::declare_interior_mutable_const)]
+const COMMAND_TIMEOUT_INIT: AtomicU64 = AtomicU64::new(0);
+
+/// The command timeout of the motor on each port in microseconds, or zero if it has none.
+static COMMAND_TIMEOUTS: [AtomicU64; V5_MAX_DEVICE_PORTS] =
+    [COMMAND_TIMEOUT_INIT; V5_MAX_DEVICE_PORTS];
+
+/// The time of the last command sent to the motor on each port in microseconds, or [`u64::MAX`] if
+/// the motor has already been stopped for exceeding its command timeout.
+static LAST_COMMANDS: [AtomicU64; V5_MAX_DEVICE_PORTS] =
+    [COMMAND_TIMEOUT_INIT; V5_MAX_DEVICE_PORTS];
+
+#[allow(clippy::declare_interior_mutable_const)]
+const LENIENT_WARNING_INIT: AtomicBool = AtomicBool::new(false);
 
-use super::{SmartDevice, SmartDeviceTimestamp, SmartDeviceType, SmartPort};
-use crate::{position::Position, PortError};
+/// Whether a [lenient](Motor::new_lenient) motor on each port has already warned about being
+/// unavailable since it was last connected.
+static LENIENT_WARNINGS: [AtomicBool; V5_MAX_DEVICE_PORTS] =
+    [LENIENT_WARNING_INIT; V5_MAX_DEVICE_PORTS];
+
+#[allow(clippy::declare_interior_mutable_const)]
+const STALL_START_INIT: AtomicU64 = AtomicU64::new(u64::MAX);
+
+/// The time in microseconds at which the motor on each port started to look stalled, or
+/// [`u64::MAX`] if it didn't look stalled the last time [`Motor::is_stalled`] was called.
+static STALL_STARTS: [AtomicU64; V5_MAX_DEVICE_PORTS] = [STALL_START_INIT; V5_MAX_DEVICE_PORTS];
+
+/// The fraction of a motor's current limit that it must draw to be considered stalled.
+const STALL_CURRENT_RATIO: f64 = 0.8;
+
+/// The fraction of a motor's maximum speed that it must be below to be considered stalled.
+const STALL_VELOCITY_RATIO: f64 = 0.05;
+
+/// How long a motor must continuously look stalled before [`Motor::is_stalled`] reports it.
+const STALL_DURATION: Duration = Duration::from_millis(250);
+
+/// How often [`CommandTimeoutFuture`] checks for motors that have exceeded their command timeout.
+const COMMAND_TIMEOUT_CHECK_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Stops every motor that hasn't received a command within its [command timeout](Motor::set_command_timeout).
+///
+/// Each motor is only stopped once per timeout; sending the motor a new command re-arms its timeout.
+/// Ports that no longer have a motor plugged into them are skipped, so a device that has since been
+/// plugged in is left alone. [`watch_command_timeouts`] calls this function periodically in the
+/// background.
+pub fn enforce_command_timeouts() {
+    let now = time::micros();
+    let mut device_types: Option<[V5_DeviceType; V5_MAX_DEVICE_PORTS]> = None;
+
+    for (index, timeout) in COMMAND_TIMEOUTS.iter().enumerate() {
+        let timeout = timeout.load(Ordering::Relaxed);
+        if timeout == 0 {
+            continue;
+        }
+
+        let last_command = LAST_COMMANDS[index].load(Ordering::Relaxed);
+        if last_command == u64::MAX || now.saturating_sub(last_command) < timeout {
+            continue;
+        }
+
+        if LAST_COMMANDS[index]
+            .compare_exchange(last_command, u64::MAX, Ordering::Relaxed, Ordering::Relaxed)
+            .is_ok()
+        {
+            let device_types = device_types.get_or_insert_with(|| {
+                let mut device_types: [V5_DeviceType; V5_MAX_DEVICE_PORTS] =
+                    unsafe { core::mem::zeroed() };
+                unsafe {
+                    vexDeviceGetStatus(device_types.as_mut_ptr());
+                }
+                device_types
+            });
+            if device_types[index] != V5_DeviceType::kDeviceTypeMotorSensor {
+                continue;
+            }
+
+            unsafe {
+                let device = vexDeviceGetByIndex(index as u32);
+                vexDeviceMotorBrakeModeSet(device, V5MotorBrakeMode::kV5MotorBrakeModeCoast);
+                vexDeviceMotorVoltageSet(device, 0);
+            }
+        }
+    }
+}
+
+/// Returns a future that enforces [motor command timeouts](Motor::set_command_timeout) until it is
+/// dropped.
+///
+/// Command timeouts only take effect while this future is being polled, so it should be spawned as
+/// a background task at the start of the program. Timeouts are checked every 5 milliseconds, and the
+/// future sleeps in between checks. The future never completes.
+///
+/// # Examples
+///
+/// ```no_run
+/// spawn(motor::watch_command_timeouts()).detach();
+///
+/// let mut motor = Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward);
+/// motor.set_command_timeout(Some(Duration::from_millis(100)));
+/// ```
+pub const fn watch_command_timeouts() -> CommandTimeoutFuture {
+    CommandTimeoutFuture { sleep: None }
+}
+
+/// A future that enforces motor command timeouts in the background.
+///
+/// See [`watch_command_timeouts`] for more information.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct CommandTimeoutFuture {
+    sleep: Option<Sleep>,
+}
+
+impl Future for CommandTimeoutFuture {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            if let Some(sleep) = &mut self.sleep {
+                if Pin::new(sleep).poll(cx).is_pending() {
+                    return Poll::Pending;
+                }
+            }
+
+            enforce_command_timeouts();
+            self.sleep = Some(sleep(COMMAND_TIMEOUT_CHECK_INTERVAL));
+        }
+    }
+}
+
+/// A future that moves a [`Motor`] to a position and resolves once it has arrived.
+///
+/// See [`Motor::move_to`] for more information.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct MoveToFuture<'a> {
+    motor: &'a mut Motor,
+    target: Position,
+    velocity: i32,
+    tolerance: Position,
+    timeout: Duration,
+    started: Option<Instant>,
+}
+
+impl MoveToFuture<'_> {
+    /// Sets how close the motor must be to its target for the move to complete.
+    pub fn tolerance(mut self, tolerance: Position) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Sets how long to wait for the motor to reach its target before giving up.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+impl Future for MoveToFuture<'_> {
+    type Output = Result<(), MotorError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+
+        let started = match this.started {
+            Some(started) => started,
+            None => {
+                if let Err(err) = this.motor.set_position_target(this.target, this.velocity) {
+                    return Poll::Ready(Err(err));
+                }
+
+                *this.started.insert(Instant::now())
+            }
+        };
+
+        let error = match this.motor.position() {
+            Ok(position) => position - this.target,
+            Err(err) => return Poll::Ready(Err(err)),
+        };
+        let error = if error < Position::default() {
+            -error
+        } else {
+            error
+        };
+
+        if error <= this.tolerance {
+            return Poll::Ready(Ok(()));
+        }
+
+        if started.elapsed() >= this.timeout {
+            return Poll::Ready(Err(MotorError::MoveTimedOut));
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
 
 /// The basic motor struct.
+///
+/// # Dropping
+///
+/// When a [`Motor`] is dropped, the physical motor is commanded to stop so that it doesn't keep
+/// running at its last target after nothing owns it anymore. If you intentionally want a motor to
+/// keep running past the end of a scope, either keep it in a longer-lived owner or pass it to
+/// [`core::mem::forget`].
 #[derive(Debug, PartialEq)]
 pub struct Motor {
     port: SmartPort,
@@ -30,6 +270,10 @@ pub struct Motor {
     device: V5_DeviceT,
 
     motor_type: MotorType,
+    lenient: bool,
+
+    startup_ramp: Option<Duration>,
+    ramp_start: Option<Instant>,
 }
 
 // SAFETY: Required because we store a raw pointer to the device handle to avoid it getting from the
@@ -66,45 +310,6 @@ pub enum MotorControl {
     Position(Position, i32),
 }
 
-/// Represents a possible direction that a motor can be configured as.
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub enum Direction {
-    /// Motor rotates in the forward direction.
-    Forward,
-
-    /// Motor rotates in the reverse direction.
-    Reverse,
-}
-
-impl Direction {
-    /// Returns `true` if the level is [`Forward`](Direction::Forward).
-    pub const fn is_forward(&self) -> bool {
-        match self {
-            Self::Forward => true,
-            Self::Reverse => false,
-        }
-    }
-
-    /// Returns `true` if the level is [`Reverse`](Direction::Reverse).
-    pub const fn is_reverse(&self) -> bool {
-        match self {
-            Self::Forward => false,
-            Self::Reverse => true,
-        }
-    }
-}
-
-impl core::ops::Not for Direction {
-    type Output = Self;
-
-    fn not(self) -> Self::Output {
-        match self {
-            Self::Forward => Self::Reverse,
-            Self::Reverse => Self::Forward,
-        }
-    }
-}
-
 /// Represents the type of a smart motor.
 /// Either a 11W (V5) or 5.5W (EXP) motor.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -128,8 +333,8 @@ impl MotorType {
     /// Gets the maximum voltage for a motor of this type.
     pub const fn max_voltage(&self) -> f64 {
         match self {
-            MotorType::Exp => Motor::V5_MAX_VOLTAGE,
-            MotorType::V5 => Motor::EXP_MAX_VOLTAGE,
+            MotorType::Exp => Motor::EXP_MAX_VOLTAGE,
+            MotorType::V5 => Motor::V5_MAX_VOLTAGE,
         }
     }
 }
@@ -140,6 +345,16 @@ impl Motor {
     /// The maximum voltage value that can be sent to a EXP [`Motor`].
     pub const EXP_MAX_VOLTAGE: f64 = 10.0;
 
+    /// The battery voltage that [`Motor::set_voltage_compensated`] scales its output relative to.
+    pub const NOMINAL_BATTERY_VOLTAGE: f64 = 12.8;
+
+    /// The default distance from its target that a motor must be within for [`Motor::move_to`] to
+    /// complete.
+    pub const DEFAULT_MOVE_TOLERANCE: Position = Position::from_ticks(2, 360);
+
+    /// The default amount of time that [`Motor::move_to`] waits for a motor to reach its target.
+    pub const DEFAULT_MOVE_TIMEOUT: Duration = Duration::from_secs(5);
+
     /// The rate at which data can be read from a [`Motor`].
     pub const DATA_READ_INTERVAL: Duration = Duration::from_millis(10);
 
@@ -168,11 +383,16 @@ impl Motor {
             vexDeviceMotorGearingSet(device, gearset.into());
         }
 
+        STALL_STARTS[(port.number() - 1) as usize].store(u64::MAX, Ordering::Relaxed);
+
         Self {
             port,
             target: MotorControl::Voltage(0.0),
             device,
             motor_type,
+            lenient: false,
+            startup_ramp: None,
+            ramp_start: None,
         }
     }
 
@@ -187,14 +407,76 @@ impl Motor {
         Self::new_with_type(port, Gearset::Green, direction, MotorType::Exp)
     }
 
+    /// Creates a new 11W (V5) Smart Motor that ignores errors caused by it being unplugged.
+    ///
+    /// If the motor is disconnected (or a different device is plugged into its port), a warning is
+    /// logged and every method acts as if the motor were connected rather than returning a
+    /// [`PortError`]. Nothing is sent to the port while the motor is unavailable, so a different
+    /// device plugged into it is left alone. Commands are silently dropped (although
+    /// [`Motor::target`] is still updated), and readings return zero, or the closest equivalent: no
+    /// status or fault flags, [`Direction::Forward`], and [`Gearset::Green`]. The warning is logged
+    /// again each time the motor goes from connected to disconnected.
+    ///
+    /// This is intended for testing code on a partially assembled robot.
+    ///
+    /// # Warning
+    ///
+    /// Lenient motors hide hardware failures, so they must **never** be used in competition code. A
+    /// motor that comes unplugged during a match should be reported, not ignored.
+    pub fn new_lenient(port: SmartPort, gearset: Gearset, direction: Direction) -> Self {
+        let mut motor = Self::new(port, gearset, direction);
+        motor.lenient = true;
+        LENIENT_WARNINGS[motor.port_index()].store(false, Ordering::Relaxed);
+
+        _ = SmartDevice::validate_port(&motor);
+        motor
+    }
+
+    /// Returns `true` if the motor was created with [`Motor::new_lenient`].
+    pub const fn is_lenient(&self) -> bool {
+        self.lenient
+    }
+
+    /// Checks that the motor is plugged in before it is accessed.
+    ///
+    /// Returns `Ok(false)` rather than an error if a [lenient](Motor::new_lenient) motor is
+    /// unavailable, in which case the caller must skip accessing the motor and act as if it had
+    /// succeeded.
+    fn check_port(&self) -> Result<bool, PortError> {
+        match self.validate_port() {
+            Ok(()) => Ok(true),
+            Err(_) if self.lenient => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+
     /// Sets the target that the motor should attempt to reach.
     ///
     /// This could be a voltage, velocity, position, or even brake mode.
+    ///
+    /// If [disabled safety](set_disabled_safety) is enabled and the robot is disabled by competition control,
+    /// targets other than [`MotorControl::Brake`] are ignored.
     pub fn set_target(&mut self, target: MotorControl) -> Result<(), MotorError> {
+        let connected = self.check_port()?;
         let gearset = self.gearset()?;
+
+        LAST_COMMANDS[self.port_index()].store(time::micros(), Ordering::Relaxed);
+
+        if disabled_safety()
+            && competition::mode() == CompetitionMode::Disabled
+            && !matches!(target, MotorControl::Brake(_))
+        {
+            return Ok(());
+        }
+
+        let output = self.ramp_output(target);
         self.target = target;
 
-        match target {
+        if !connected {
+            return Ok(());
+        }
+
+        match output {
             MotorControl::Brake(mode) => unsafe {
                 vexDeviceMotorBrakeModeSet(self.device, mode.into());
                 // Force motor into braking by putting it into velocity control with a 0rpm setpoint.
@@ -230,6 +512,100 @@ impl Motor {
         Ok(())
     }
 
+    /// Sets how long the motor takes to ramp up to its commanded output after being stopped.
+    ///
+    /// Sending full power to a mechanism that is sitting still puts a large shock load on its
+    /// gears. With a startup ramp, the first voltage or velocity command after the motor was
+    /// stopped is scaled up linearly from zero over `ramp`, after which commands are passed through
+    /// unchanged. The ramp only applies when starting from a stop, so unlike a
+    /// [`SlewRateLimiter`](crate::control::slew::SlewRateLimiter), changes in output while the motor is
+    /// already running take effect immediately.
+    ///
+    /// The motor counts as stopped when its target is braking, or a voltage or velocity of zero.
+    /// Position targets have their velocity ramped the same way.
+    ///
+    /// Pass [`None`] (or [`Duration::ZERO`]) to disable the ramp, which is the default.
+    ///
+    /// # Note
+    ///
+    /// The ramp is applied when commands are sent, so the motor must be commanded repeatedly (as in
+    /// a control loop) for its output to rise. A single call to [`Motor::set_voltage`] only sets
+    /// the output to the start of the ramp.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// intake.set_startup_ramp(Some(Duration::from_millis(250)));
+    ///
+    /// loop {
+    ///     let state = controller.state()?;
+    ///
+    ///     // Ramps up to 12V over the first 250ms after the intake starts moving.
+    ///     intake.set_voltage(if state.right_trigger_1.is_pressed() { 12.0 } else { 0.0 })?;
+    ///     sleep(Duration::from_millis(10)).await;
+    /// }
+    /// ```
+    pub fn set_startup_ramp(&mut self, ramp: Option<Duration>) {
+        self.startup_ramp = ramp.filter(|ramp| !ramp.is_zero());
+        self.ramp_start = None;
+    }
+
+    /// Returns the startup ramp set with [`Motor::set_startup_ramp`], if any.
+    pub const fn startup_ramp(&self) -> Option<Duration> {
+        self.startup_ramp
+    }
+
+    /// Returns `true` if the motor is currently ramping up to its commanded output.
+    ///
+    /// See [`Motor::set_startup_ramp`] for more information.
+    pub fn is_ramping(&self) -> bool {
+        match (self.startup_ramp, self.ramp_start) {
+            (Some(ramp), Some(start)) => start.elapsed() < ramp,
+            _ => false,
+        }
+    }
+
+    /// Scales `target` according to the startup ramp, starting a new ramp if the motor was stopped.
+    fn ramp_output(&mut self, target: MotorControl) -> MotorControl {
+        let Some(ramp) = self.startup_ramp else {
+            return target;
+        };
+
+        let is_stopped = |target: MotorControl| match target {
+            MotorControl::Brake(_) => true,
+            MotorControl::Voltage(volts) => volts == 0.0,
+            MotorControl::Velocity(rpm) | MotorControl::Position(_, rpm) => rpm == 0,
+        };
+
+        if is_stopped(target) {
+            self.ramp_start = None;
+            return target;
+        }
+
+        if self.ramp_start.is_none() && is_stopped(self.target) {
+            self.ramp_start = Some(Instant::now());
+        }
+
+        let Some(start) = self.ramp_start else {
+            return target;
+        };
+
+        let progress = start.elapsed().as_secs_f64() / ramp.as_secs_f64();
+        if progress >= 1.0 {
+            self.ramp_start = None;
+            return target;
+        }
+
+        match target {
+            MotorControl::Voltage(volts) => MotorControl::Voltage(volts * progress),
+            MotorControl::Velocity(rpm) => MotorControl::Velocity((rpm as f64 * progress) as i32),
+            MotorControl::Position(position, rpm) => {
+                MotorControl::Position(position, (rpm as f64 * progress) as i32)
+            }
+            MotorControl::Brake(_) => target,
+        }
+    }
+
     /// Sets the motors target to a given [`BrakeMode`].
     pub fn brake(&mut self, mode: BrakeMode) -> Result<(), MotorError> {
         self.set_target(MotorControl::Brake(mode))
@@ -252,6 +628,29 @@ impl Motor {
         self.set_target(MotorControl::Voltage(volts))
     }
 
+    /// Sets the motor's output voltage, compensating for the current battery voltage.
+    ///
+    /// As the battery discharges, a raw voltage command produces less torque than it would on a fully
+    /// charged battery. This function scales `volts` by the ratio of [`Motor::NOMINAL_BATTERY_VOLTAGE`]
+    /// to the battery's measured voltage, so that the effective output remains consistent across battery
+    /// levels. The scaled voltage is clamped to the motor's [maximum voltage](Motor::max_voltage).
+    ///
+    /// The battery voltage is read on every call to this function.
+    pub fn set_voltage_compensated(&mut self, volts: f64) -> Result<(), MotorError> {
+        let battery_voltage = battery::voltage();
+        let max_voltage = self.max_voltage();
+
+        // Don't scale when the battery reading is unavailable, since this would otherwise
+        // result in a division by zero.
+        let compensated = if battery_voltage > 0.0 {
+            volts * (Self::NOMINAL_BATTERY_VOLTAGE / battery_voltage)
+        } else {
+            volts
+        };
+
+        self.set_voltage(compensated.clamp(-max_voltage, max_voltage))
+    }
+
     /// Sets an absolute position target for the motor to attempt to reach.
     pub fn set_position_target(
         &mut self,
@@ -261,14 +660,45 @@ impl Motor {
         self.set_target(MotorControl::Position(position, velocity))
     }
 
+    /// Moves the motor to an absolute position, returning a future that resolves once the motor
+    /// has reached it.
+    ///
+    /// This sets a [`MotorControl::Position`] target when the future is first polled, then waits
+    /// until the motor is within [`Motor::DEFAULT_MOVE_TOLERANCE`] of `target`. If the motor doesn't
+    /// reach its target within [`Motor::DEFAULT_MOVE_TIMEOUT`] (for example, because the mechanism
+    /// is stalled), the future resolves with [`MotorError::MoveTimedOut`]. Both of these can be
+    /// changed with [`MoveToFuture::tolerance`] and [`MoveToFuture::timeout`].
+    ///
+    /// The motor keeps holding its target after the future resolves.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// // Raise an arm, then lower it back down.
+    /// arm.move_to(Position::from_degrees(90.0), 100).await?;
+    /// arm.move_to(Position::from_degrees(0.0), 100)
+    ///     .timeout(Duration::from_secs(2))
+    ///     .await?;
+    /// ```
+    pub fn move_to(&mut self, target: Position, velocity: i32) -> MoveToFuture<'_> {
+        MoveToFuture {
+            motor: self,
+            target,
+            velocity,
+            tolerance: Self::DEFAULT_MOVE_TOLERANCE,
+            timeout: Self::DEFAULT_MOVE_TIMEOUT,
+            started: None,
+        }
+    }
+
     /// Changes the output velocity for a profiled movement (motor_move_absolute or motor_move_relative).
     ///
     /// This will have no effect if the motor is not following a profiled movement.
     pub fn update_profiled_velocity(&mut self, velocity: i32) -> Result<(), MotorError> {
-        self.validate_port()?;
-
-        unsafe {
-            vexDeviceMotorVelocityUpdate(self.device, velocity);
+        if self.check_port()? {
+            unsafe {
+                vexDeviceMotorVelocityUpdate(self.device, velocity);
+            }
         }
 
         if let MotorControl::Position(position, _) = self.target {
@@ -288,19 +718,70 @@ impl Motor {
         if self.motor_type.is_exp() {
             return Err(MotorError::SetGearsetExp);
         }
-        self.validate_port()?;
-        unsafe {
-            vexDeviceMotorGearingSet(self.device, gearset.into());
+        if self.check_port()? {
+            unsafe {
+                vexDeviceMotorGearingSet(self.device, gearset.into());
+            }
         }
         Ok(())
     }
 
+    /// Sets how long the motor may go without receiving a new command before it is stopped.
+    ///
+    /// This is a safety net for when the code responsible for controlling a motor stops running, such
+    /// as when a task panics or gets stuck. If no new target is set through [`Motor::set_target`] (or
+    /// any of the functions built on it) within `timeout`, the motor is coasted to zero voltage. The
+    /// next command re-arms the timeout. Pass `None` to disable the timeout, which is the default.
+    ///
+    /// Timeouts are enforced by [`watch_command_timeouts`], which must be running in the background
+    /// for this to have any effect. Note that [`Motor::target`] isn't updated when the motor is
+    /// stopped by a timeout.
+    pub fn set_command_timeout(&mut self, timeout: Option<Duration>) {
+        let index = self.port_index();
+
+        LAST_COMMANDS[index].store(time::micros(), Ordering::Relaxed);
+        COMMAND_TIMEOUTS[index].store(
+            timeout.map_or(0, |timeout| (timeout.as_micros() as u64).max(1)),
+            Ordering::Relaxed,
+        );
+    }
+
+    /// Returns the motor's command timeout, if it has one.
+    ///
+    /// See [`Motor::set_command_timeout`] for more information.
+    pub fn command_timeout(&self) -> Option<Duration> {
+        match COMMAND_TIMEOUTS[self.port_index()].load(Ordering::Relaxed) {
+            0 => None,
+            micros => Some(Duration::from_micros(micros)),
+        }
+    }
+
+    /// Returns the index of the motor's port into the per-port command timeout tables.
+    fn port_index(&self) -> usize {
+        (self.port.number() - 1) as usize
+    }
+
+    /// Makes a best-effort attempt to stop the motor by coasting it to zero voltage, ignoring any errors.
+    fn stop(&self) {
+        if self.validate_port().is_ok() {
+            unsafe {
+                vexDeviceMotorBrakeModeSet(
+                    self.device,
+                    vex_sdk::V5MotorBrakeMode::kV5MotorBrakeModeCoast,
+                );
+                vexDeviceMotorVoltageSet(self.device, 0);
+            }
+        }
+    }
+
     /// Gets the gearset of the motor.
     pub fn gearset(&self) -> Result<Gearset, MotorError> {
         if self.motor_type.is_exp() {
             return Ok(Gearset::Green);
         }
-        self.validate_port()?;
+        if !self.check_port()? {
+            return Ok(Gearset::Green);
+        }
         Ok(unsafe { vexDeviceMotorGearingGet(self.device) }.into())
     }
 
@@ -324,30 +805,42 @@ impl Motor {
 
     /// Gets the estimated angular velocity (RPM) of the motor.
     pub fn velocity(&self) -> Result<i32, MotorError> {
-        self.validate_port()?;
+        if !self.check_port()? {
+            return Ok(0);
+        }
         Ok(unsafe { vexDeviceMotorVelocityGet(self.device) })
     }
 
     /// Returns the power drawn by the motor in Watts.
     pub fn power(&self) -> Result<f64, MotorError> {
-        self.validate_port()?;
+        if !self.check_port()? {
+            return Ok(0.0);
+        }
         Ok(unsafe { vexDeviceMotorPowerGet(self.device) })
     }
 
     /// Returns the torque output of the motor in Nm.
     pub fn torque(&self) -> Result<f64, MotorError> {
-        self.validate_port()?;
+        if !self.check_port()? {
+            return Ok(0.0);
+        }
         Ok(unsafe { vexDeviceMotorTorqueGet(self.device) })
     }
 
     /// Returns the voltage the motor is drawing in volts.
     pub fn voltage(&self) -> Result<f64, MotorError> {
-        self.validate_port()?;
+        if !self.check_port()? {
+            return Ok(0.0);
+        }
         Ok(unsafe { vexDeviceMotorVoltageGet(self.device) } as f64 / 1000.0)
     }
 
     /// Returns the current position of the motor.
     pub fn position(&self) -> Result<Position, MotorError> {
+        if !self.check_port()? {
+            return Ok(Position::default());
+        }
+
         let gearset = self.gearset()?;
         Ok(Position::from_ticks(
             unsafe { vexDeviceMotorPositionGet(self.device) } as i64,
@@ -359,7 +852,9 @@ impl Motor {
     /// along with a timestamp of the internal clock of the motor indicating when the
     /// data was recorded.
     pub fn raw_position(&self) -> Result<(i32, SmartDeviceTimestamp), MotorError> {
-        self.validate_port()?;
+        if !self.check_port()? {
+            return Ok((0, SmartDeviceTimestamp(0)));
+        }
 
         let mut timestamp: u32 = 0;
         let ticks = unsafe { vexDeviceMotorPositionRawGet(self.device, &mut timestamp) };
@@ -369,7 +864,9 @@ impl Motor {
 
     /// Returns the electrical current draw of the motor in amps.
     pub fn current(&self) -> Result<f64, MotorError> {
-        self.validate_port()?;
+        if !self.check_port()? {
+            return Ok(0.0);
+        }
         Ok(unsafe { vexDeviceMotorCurrentGet(self.device) } as f64 / 1000.0)
     }
 
@@ -379,40 +876,147 @@ impl Motor {
     /// drawing no electrical power, and an efficiency of 0.0 means that the motor
     /// is drawing power but not moving.
     pub fn efficiency(&self) -> Result<f64, MotorError> {
-        self.validate_port()?;
+        if !self.check_port()? {
+            return Ok(0.0);
+        }
 
         Ok(unsafe { vexDeviceMotorEfficiencyGet(self.device) } / 100.0)
     }
 
+    /// Reads all of the motor's commonly used telemetry at once.
+    ///
+    /// The V5 SDK doesn't provide a single call that returns all of a motor's readings. However,
+    /// each reading is served from data that VEXos has already received from the motor, so the
+    /// individual reads are cheap, and most of the overhead of calling several getters comes from
+    /// checking that the motor is still connected before every read. This function checks the port
+    /// once, then takes every reading in [`MotorSnapshot`] back to back, which is faster than
+    /// calling each getter separately and keeps the readings as close together in time as possible.
+    ///
+    /// [`Motor::status`] and tuning/limit values are not included in the snapshot.
+    pub fn snapshot(&self) -> Result<MotorSnapshot, MotorError> {
+        if !self.check_port()? {
+            return Ok(MotorSnapshot {
+                position: Position::default(),
+                velocity: 0,
+                current: 0.0,
+                voltage: 0.0,
+                power: 0.0,
+                torque: 0.0,
+                efficiency: 0.0,
+                temperature: 0.0,
+                faults: MotorFaults::empty(),
+            });
+        }
+
+        let gearset = if self.motor_type.is_exp() {
+            Gearset::Green
+        } else {
+            unsafe { vexDeviceMotorGearingGet(self.device) }.into()
+        };
+
+        unsafe {
+            Ok(MotorSnapshot {
+                position: Position::from_ticks(
+                    vexDeviceMotorPositionGet(self.device) as i64,
+                    gearset.ticks_per_revolution(),
+                ),
+                velocity: vexDeviceMotorVelocityGet(self.device),
+                current: vexDeviceMotorCurrentGet(self.device) as f64 / 1000.0,
+                voltage: vexDeviceMotorVoltageGet(self.device) as f64 / 1000.0,
+                power: vexDeviceMotorPowerGet(self.device),
+                torque: vexDeviceMotorTorqueGet(self.device),
+                efficiency: vexDeviceMotorEfficiencyGet(self.device) / 100.0,
+                temperature: vexDeviceMotorTemperatureGet(self.device),
+                faults: MotorFaults::from_bits_retain(vexDeviceMotorFaultsGet(self.device)),
+            })
+        }
+    }
+
+    /// Returns `true` if the motor appears to be stalled.
+    ///
+    /// A motor is considered stalled when it draws at least 80% of its
+    /// [current limit](Motor::current_limit) while turning slower than 5% of its gearset's maximum
+    /// speed, continuously for at least 250 milliseconds. This usually means that the mechanism it
+    /// drives is jammed, such as an intake caught on a game piece, and that the motor should back off
+    /// before it overheats.
+    ///
+    /// The stall window is tracked between calls, so this function must be called regularly (for
+    /// example, in the same loop that commands the motor) to detect stalls. A motor that isn't
+    /// commanded to move is never considered stalled, since it won't draw much current.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// loop {
+    ///     if intake.is_stalled()? {
+    ///         // Briefly reverse to clear the jam.
+    ///         intake.set_voltage(-6.0)?;
+    ///         sleep(Duration::from_millis(200)).await;
+    ///     }
+    ///
+    ///     intake.set_voltage(12.0)?;
+    ///     sleep(Duration::from_millis(10)).await;
+    /// }
+    /// ```
+    pub fn is_stalled(&self) -> Result<bool, MotorError> {
+        let current = self.current()?;
+        let current_limit = self.current_limit()?;
+        let velocity = self.velocity()?;
+        let max_rpm = self.gearset()?.max_rpm();
+
+        let stall_start = &STALL_STARTS[self.port_index()];
+
+        if current < current_limit * STALL_CURRENT_RATIO
+            || f64::from(velocity.abs()) > max_rpm * STALL_VELOCITY_RATIO
+        {
+            stall_start.store(u64::MAX, Ordering::Relaxed);
+            return Ok(false);
+        }
+
+        let now = time::micros();
+        let start = match stall_start.load(Ordering::Relaxed) {
+            u64::MAX => {
+                stall_start.store(now, Ordering::Relaxed);
+                now
+            }
+            start => start,
+        };
+
+        Ok(now.saturating_sub(start) >= STALL_DURATION.as_micros() as u64)
+    }
+
     /// Sets the current encoder position to zero without moving the motor.
     /// Analogous to taring or resetting the encoder to the current position.
     pub fn reset_position(&mut self) -> Result<(), MotorError> {
-        self.validate_port()?;
-        unsafe { vexDeviceMotorPositionReset(self.device) }
+        if self.check_port()? {
+            unsafe { vexDeviceMotorPositionReset(self.device) }
+        }
         Ok(())
     }
 
     /// Sets the current encoder position to the given position without moving the motor.
     /// Analogous to taring or resetting the encoder so that the new position is equal to the given position.
     pub fn set_position(&mut self, position: Position) -> Result<(), MotorError> {
-        self.validate_port()?;
-        unsafe { vexDeviceMotorPositionSet(self.device, position.as_degrees()) }
+        if self.check_port()? {
+            unsafe { vexDeviceMotorPositionSet(self.device, position.as_degrees()) }
+        }
         Ok(())
     }
 
     /// Sets the current limit for the motor in amps.
     pub fn set_current_limit(&mut self, limit: f64) -> Result<(), MotorError> {
-        self.validate_port()?;
-        unsafe { vexDeviceMotorCurrentLimitSet(self.device, (limit * 1000.0) as i32) }
+        if self.check_port()? {
+            unsafe { vexDeviceMotorCurrentLimitSet(self.device, (limit * 1000.0) as i32) }
+        }
         Ok(())
     }
 
     /// Sets the voltage limit for the motor in volts.
     pub fn set_voltage_limit(&mut self, limit: f64) -> Result<(), MotorError> {
-        self.validate_port()?;
-
-        unsafe {
-            vexDeviceMotorVoltageLimitSet(self.device, (limit * 1000.0) as i32);
+        if self.check_port()? {
+            unsafe {
+                vexDeviceMotorVoltageLimitSet(self.device, (limit * 1000.0) as i32);
+            }
         }
 
         Ok(())
@@ -420,25 +1024,33 @@ impl Motor {
 
     /// Gets the current limit for the motor in amps.
     pub fn current_limit(&self) -> Result<f64, MotorError> {
-        self.validate_port()?;
+        if !self.check_port()? {
+            return Ok(0.0);
+        }
         Ok(unsafe { vexDeviceMotorCurrentLimitGet(self.device) } as f64 / 1000.0)
     }
 
     /// Gets the voltage limit for the motor if one has been explicitly set.
     pub fn voltage_limit(&self) -> Result<f64, MotorError> {
-        self.validate_port()?;
+        if !self.check_port()? {
+            return Ok(0.0);
+        }
         Ok(unsafe { vexDeviceMotorVoltageLimitGet(self.device) } as f64 / 1000.0)
     }
 
     /// Returns the internal temperature recorded by the motor in increments of 5 °C.
     pub fn temperature(&self) -> Result<f64, MotorError> {
-        self.validate_port()?;
+        if !self.check_port()? {
+            return Ok(0.0);
+        }
         Ok(unsafe { vexDeviceMotorTemperatureGet(self.device) })
     }
 
     /// Get the status flags of a motor.
     pub fn status(&self) -> Result<MotorStatus, MotorError> {
-        self.validate_port()?;
+        if !self.check_port()? {
+            return Ok(MotorStatus::empty());
+        }
 
         let status = MotorStatus::from_bits_retain(unsafe { vexDeviceMotorFlagsGet(self.device) });
 
@@ -453,7 +1065,9 @@ impl Motor {
 
     /// Get the fault flags of the motor.
     pub fn faults(&self) -> Result<MotorFaults, MotorError> {
-        self.validate_port()?;
+        if !self.check_port()? {
+            return Ok(MotorFaults::empty());
+        }
 
         Ok(MotorFaults::from_bits_retain(unsafe {
             vexDeviceMotorFaultsGet(self.device)
@@ -482,10 +1096,10 @@ impl Motor {
 
     /// Set the [`Direction`] of this motor.
     pub fn set_direction(&mut self, direction: Direction) -> Result<(), MotorError> {
-        self.validate_port()?;
-
-        unsafe {
-            vexDeviceMotorReverseFlagSet(self.device, direction.is_reverse());
+        if self.check_port()? {
+            unsafe {
+                vexDeviceMotorReverseFlagSet(self.device, direction.is_reverse());
+            }
         }
 
         Ok(())
@@ -493,7 +1107,9 @@ impl Motor {
 
     /// Get the [`Direction`] of this motor.
     pub fn direction(&self) -> Result<Direction, MotorError> {
-        self.validate_port()?;
+        if !self.check_port()? {
+            return Ok(Direction::Forward);
+        }
 
         Ok(match unsafe { vexDeviceMotorReverseFlagGet(self.device) } {
             false => Direction::Forward,
@@ -517,10 +1133,10 @@ impl Motor {
         &mut self,
         constants: MotorTuningConstants,
     ) -> Result<(), MotorError> {
-        self.validate_port()?;
-
-        let mut constants = V5_DeviceMotorPid::from(constants);
-        unsafe { vexDeviceMotorVelocityPidSet(self.device, &mut constants) }
+        if self.check_port()? {
+            let mut constants = V5_DeviceMotorPid::from(constants);
+            unsafe { vexDeviceMotorVelocityPidSet(self.device, &mut constants) }
+        }
 
         Ok(())
     }
@@ -541,10 +1157,10 @@ impl Motor {
         &mut self,
         constants: MotorTuningConstants,
     ) -> Result<(), MotorError> {
-        self.validate_port()?;
-
-        let mut constants = V5_DeviceMotorPid::from(constants);
-        unsafe { vexDeviceMotorPositionPidSet(self.device, &mut constants) }
+        if self.check_port()? {
+            let mut constants = V5_DeviceMotorPid::from(constants);
+            unsafe { vexDeviceMotorPositionPidSet(self.device, &mut constants) }
+        }
 
         Ok(())
     }
@@ -558,13 +1174,85 @@ impl SmartDevice for Motor {
     fn device_type(&self) -> SmartDeviceType {
         SmartDeviceType::Motor
     }
+
+    fn validate_port(&self) -> Result<(), PortError> {
+        let result = validate_port(self.port_number(), self.device_type());
+        if !self.lenient {
+            return result;
+        }
+
+        let warned = &LENIENT_WARNINGS[self.port_index()];
+        match &result {
+            Ok(()) => warned.store(false, Ordering::Relaxed),
+            Err(error) => {
+                if !warned.swap(true, Ordering::Relaxed) {
+                    vexide_core::io::warn!(
+                        "Motor on port {} is unavailable ({error}), ignoring.",
+                        self.port_number()
+                    );
+                }
+            }
+        }
+
+        result
+    }
 }
 impl From<Motor> for SmartPort {
     fn from(device: Motor) -> Self {
-        device.port
+        device.stop();
+        COMMAND_TIMEOUTS[device.port_index()].store(0, Ordering::Relaxed);
+
+        // `Motor` implements `Drop`, so its port can't be moved out of it directly.
+        let device = ManuallyDrop::new(device);
+
+        // SAFETY: `device` is never used again after its port is read out of it, and none of its
+        // other fields need to be dropped.
+        unsafe { core::ptr::read(&device.port) }
     }
 }
 
+impl Drop for Motor {
+    /// Stops the motor when it is dropped.
+    fn drop(&mut self) {
+        self.stop();
+        COMMAND_TIMEOUTS[self.port_index()].store(0, Ordering::Relaxed);
+    }
+}
+
+/// A set of readings taken from a [`Motor`] at the same time.
+///
+/// See [`Motor::snapshot`] for more information. Each field has the same units as the getter that
+/// shares its name.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MotorSnapshot {
+    /// The position of the motor. See [`Motor::position`].
+    pub position: Position,
+
+    /// The estimated angular velocity of the motor in RPM. See [`Motor::velocity`].
+    pub velocity: i32,
+
+    /// The electrical current draw of the motor in amps. See [`Motor::current`].
+    pub current: f64,
+
+    /// The voltage the motor is drawing in volts. See [`Motor::voltage`].
+    pub voltage: f64,
+
+    /// The power drawn by the motor in Watts. See [`Motor::power`].
+    pub power: f64,
+
+    /// The torque output of the motor in Nm. See [`Motor::torque`].
+    pub torque: f64,
+
+    /// The efficiency of the motor from a range of [0.0, 1.0]. See [`Motor::efficiency`].
+    pub efficiency: f64,
+
+    /// The internal temperature of the motor in °C. See [`Motor::temperature`].
+    pub temperature: f64,
+
+    /// The fault flags of the motor. See [`Motor::faults`].
+    pub faults: MotorFaults,
+}
+
 /// Determines how a motor should act when braking.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum BrakeMode {
@@ -795,4 +1483,7 @@ pub enum MotorError {
 
     /// Attempted to set a gearset on a EXP motor.
     SetGearsetExp,
+
+    /// The motor didn't reach its target position before the move timed out.
+    MoveTimedOut,
 }
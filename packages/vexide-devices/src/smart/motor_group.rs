@@ -0,0 +1,276 @@
+//! Groups of motors that act together.
+//!
+//! Mechanisms such as drivetrains and lifts are often powered by several motors that should always
+//! receive the same command. A [`MotorGroup`] owns a set of [`Motor`]s, forwards commands to all of
+//! them, and aggregates their telemetry.
+//!
+//! A group dereferences to a slice of its motors, so individual motors can still be accessed and
+//! the group can be passed anywhere a `&mut [Motor]` is expected.
+//!
+//! # Example
+//!
+//! ```no_run
+//! let mut left = MotorGroup::new(vec![
+//!     Motor::new(peripherals.port_1, Gearset::Blue, Direction::Forward),
+//!     Motor::new(peripherals.port_2, Gearset::Blue, Direction::Forward),
+//!     Motor::new(peripherals.port_3, Gearset::Blue, Direction::Reverse),
+//! ]);
+//!
+//! left.set_voltage(6.0)?;
+//!
+//! if left.any_over_temp()? {
+//!     println!("Left side is overheating! Hottest motor: {}°C", left.hottest()?);
+//! }
+//! ```
+//!
+//! # Detecting Mechanical Failures
+//!
+//! Motors that are geared together should always report roughly the same position. If one of them
+//! starts to drift away from the others, a gear has likely slipped or the motor has stopped driving
+//! the mechanism. [`MotorGroup::positions_agree`] and [`MotorGroup::position_outliers`] can be
+//! checked periodically to catch this before it ruins a match:
+//!
+//! ```no_run
+//! let tolerance = Position::from_degrees(90.0);
+//!
+//! for port in left.position_outliers(tolerance)? {
+//!     println!("Motor on port {port} disagrees with the rest of the left side!");
+//! }
+//! ```
+
+use alloc::vec::Vec;
+use core::{
+    ops::{Deref, DerefMut},
+    time::Duration,
+};
+
+use super::{
+    motor::{BrakeMode, Motor, MotorControl, MotorError},
+    SmartDevice,
+};
+use crate::position::Position;
+
+/// A group of motors that are commanded together.
+///
+/// See the [module-level documentation](self) for more information.
+#[derive(Debug, PartialEq)]
+pub struct MotorGroup {
+    motors: Vec<Motor>,
+}
+
+impl MotorGroup {
+    /// Creates a new group from a list of motors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `motors` is empty.
+    pub fn new(motors: Vec<Motor>) -> Self {
+        assert!(
+            !motors.is_empty(),
+            "A motor group must contain at least one motor."
+        );
+
+        Self { motors }
+    }
+
+    /// Consumes the group, returning its motors.
+    pub fn into_inner(self) -> Vec<Motor> {
+        self.motors
+    }
+
+    /// Runs `f` on every motor in the group.
+    ///
+    /// Every motor is commanded even if an earlier one fails, so that one disconnected motor
+    /// doesn't stop the rest of the mechanism. The first error encountered is returned.
+    fn for_each(
+        &mut self,
+        mut f: impl FnMut(&mut Motor) -> Result<(), MotorError>,
+    ) -> Result<(), MotorError> {
+        let mut result = Ok(());
+
+        for motor in &mut self.motors {
+            if let Err(error) = f(motor) {
+                if result.is_ok() {
+                    result = Err(error);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Sets the target that every motor in the group should attempt to reach.
+    ///
+    /// See [`Motor::set_target`] for more information.
+    pub fn set_target(&mut self, target: MotorControl) -> Result<(), MotorError> {
+        self.for_each(|motor| motor.set_target(target))
+    }
+
+    /// Sets every motor in the group's target to a given [`BrakeMode`].
+    pub fn brake(&mut self, mode: BrakeMode) -> Result<(), MotorError> {
+        self.for_each(|motor| motor.brake(mode))
+    }
+
+    /// Spins every motor in the group at a target velocity.
+    ///
+    /// See [`Motor::set_velocity`] for more information.
+    pub fn set_velocity(&mut self, rpm: i32) -> Result<(), MotorError> {
+        self.for_each(|motor| motor.set_velocity(rpm))
+    }
+
+    /// Sets the output voltage of every motor in the group.
+    ///
+    /// See [`Motor::set_voltage`] for more information.
+    pub fn set_voltage(&mut self, volts: f64) -> Result<(), MotorError> {
+        self.for_each(|motor| motor.set_voltage(volts))
+    }
+
+    /// Sets how long every motor in the group takes to ramp up to its commanded output after being
+    /// stopped.
+    ///
+    /// See [`Motor::set_startup_ramp`] for more information.
+    pub fn set_startup_ramp(&mut self, ramp: Option<Duration>) {
+        for motor in &mut self.motors {
+            motor.set_startup_ramp(ramp);
+        }
+    }
+
+    /// Returns `true` if any motor in the group has its over temperature flag set.
+    pub fn any_over_temp(&self) -> Result<bool, MotorError> {
+        for motor in &self.motors {
+            if motor.is_over_temperature()? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Returns the temperature of the hottest motor in the group in degrees Celsius.
+    pub fn hottest(&self) -> Result<f64, MotorError> {
+        let mut hottest = f64::NEG_INFINITY;
+
+        for motor in &self.motors {
+            hottest = hottest.max(motor.temperature()?);
+        }
+
+        Ok(hottest)
+    }
+
+    /// Returns the port numbers of every motor in the group that currently reports a fault.
+    ///
+    /// The returned list is empty if no motors are faulted. See [`Motor::faults`] for the faults
+    /// that a motor can report.
+    pub fn faulted_ports(&self) -> Result<Vec<u8>, MotorError> {
+        let mut ports = Vec::new();
+
+        for motor in &self.motors {
+            if !motor.faults()?.is_empty() {
+                ports.push(motor.port_number());
+            }
+        }
+
+        Ok(ports)
+    }
+
+    /// Returns `true` if the positions of every motor in the group are within `tolerance` of each
+    /// other.
+    ///
+    /// Positions are measured from each motor's own zero point, so this is only meaningful if every
+    /// motor's position was reset at the same time (for example, with
+    /// [`MotorGroup::reset_position`]).
+    pub fn positions_agree(&self, tolerance: Position) -> Result<bool, MotorError> {
+        let mut min = None;
+        let mut max = None;
+
+        for motor in &self.motors {
+            let position = motor.position()?;
+
+            min = Some(min.map_or(position, |min: Position| min.min(position)));
+            max = Some(max.map_or(position, |max: Position| max.max(position)));
+        }
+
+        Ok(match (min, max) {
+            (Some(min), Some(max)) => max - min <= tolerance,
+            _ => true,
+        })
+    }
+
+    /// Returns the port numbers of every motor in the group whose position differs from the
+    /// group's median position by more than `tolerance`.
+    ///
+    /// Comparing against the median means that a single slipping or failed motor is reported on its
+    /// own, without the rest of the group being flagged. In a group of only two motors there's no
+    /// way to tell which one is wrong, so both are returned if they disagree by more than twice
+    /// `tolerance`.
+    ///
+    /// As with [`MotorGroup::positions_agree`], every motor's position should have been reset at
+    /// the same time.
+    pub fn position_outliers(&self, tolerance: Position) -> Result<Vec<u8>, MotorError> {
+        let mut positions = Vec::with_capacity(self.motors.len());
+
+        for motor in &self.motors {
+            positions.push((motor.port_number(), motor.position()?));
+        }
+
+        let mut sorted = positions
+            .iter()
+            .map(|(_, position)| position.as_revolutions())
+            .collect::<Vec<_>>();
+        sorted.sort_by(f64::total_cmp);
+
+        let middle = sorted.len() / 2;
+        let median = Position::from_revolutions(if sorted.len() % 2 == 0 {
+            (sorted[middle - 1] + sorted[middle]) / 2.0
+        } else {
+            sorted[middle]
+        });
+
+        Ok(positions
+            .into_iter()
+            .filter(|&(_, position)| {
+                let difference = if position > median {
+                    position - median
+                } else {
+                    median - position
+                };
+
+                difference > tolerance
+            })
+            .map(|(port, _)| port)
+            .collect())
+    }
+
+    /// Resets the position of every motor in the group to zero.
+    ///
+    /// See [`Motor::reset_position`] for more information.
+    pub fn reset_position(&mut self) -> Result<(), MotorError> {
+        self.for_each(Motor::reset_position)
+    }
+}
+
+impl Deref for MotorGroup {
+    type Target = [Motor];
+
+    fn deref(&self) -> &Self::Target {
+        &self.motors
+    }
+}
+
+impl DerefMut for MotorGroup {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.motors
+    }
+}
+
+impl From<Vec<Motor>> for MotorGroup {
+    fn from(motors: Vec<Motor>) -> Self {
+        Self::new(motors)
+    }
+}
+
+impl<const N: usize> From<[Motor; N]> for MotorGroup {
+    fn from(motors: [Motor; N]) -> Self {
+        Self::new(motors.into())
+    }
+}
@@ -63,6 +63,8 @@ impl OpticalSensor {
 
     /// Get integration time (update rate) of the optical sensor in milliseconds, with
     /// minimum time being 3ms and the maximum time being 712ms.
+    ///
+    /// The returned value reflects any clamping done by [`OpticalSensor::set_integration_time`].
     pub fn integration_time(&self) -> Result<Duration, PortError> {
         self.validate_port()?;
 
@@ -74,7 +76,9 @@ impl OpticalSensor {
     /// Set integration time (update rate) of the optical sensor.
     ///
     /// Lower integration time results in faster update rates with lower accuracy
-    /// due to less available light being read by the sensor.
+    /// due to less available light being read by the sensor. Short integration times
+    /// generally work better in brightly lit environments, while longer integration
+    /// times help in dim lighting.
     ///
     /// Time value must be a [`Duration`] between 3 and 712 milliseconds. See
     /// <https://www.vexforum.com/t/v5-optical-sensor-refresh-rate/109632/9> for
@@ -9,8 +9,12 @@ use vex_sdk::{
     vexDeviceAbsEncPositionSet, vexDeviceAbsEncStatusGet, vexDeviceAbsEncVelocityGet, V5_DeviceT,
 };
 
-use super::{motor::Direction, SmartDevice, SmartDeviceType, SmartPort};
-use crate::{position::Position, PortError};
+use super::{SmartDevice, SmartDeviceType, SmartPort};
+use crate::{
+    position::{wrap_angle, Direction, Position},
+    sensor::Sensor,
+    PortError,
+};
 
 /// A physical rotation sensor plugged into a port.
 #[derive(Debug, PartialEq)]
@@ -156,14 +160,15 @@ impl RotationSensor {
 
     /// Get the angle of rotation measured by the sensor.
     ///
-    /// This value is reported from 0-360 degrees.
+    /// This value is reported from 0-360 degrees, wrapping back to 0 after a full revolution. For
+    /// the total unbounded rotation of the sensor, see [`RotationSensor::position`].
     pub fn angle(&self) -> Result<Position, PortError> {
         self.validate_port()?;
 
         let mut raw_angle = unsafe { vexDeviceAbsEncAngleGet(self.device) };
 
         if self.direction == Direction::Reverse {
-            raw_angle = (Self::TICKS_PER_REVOLUTION as i32) - raw_angle;
+            raw_angle = wrap_angle(-raw_angle, Self::TICKS_PER_REVOLUTION as i32);
         }
 
         Ok(Position::from_ticks(
@@ -193,6 +198,16 @@ impl RotationSensor {
     }
 }
 
+impl Sensor for RotationSensor {
+    type Reading = Position;
+    type Error = PortError;
+
+    /// Reads the sensor's absolute [position](RotationSensor::position).
+    fn read(&self) -> Result<Self::Reading, Self::Error> {
+        self.position()
+    }
+}
+
 impl SmartDevice for RotationSensor {
     fn port_number(&self) -> u8 {
         self.port.number()
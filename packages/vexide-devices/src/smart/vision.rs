@@ -21,9 +21,17 @@
 
 extern crate alloc;
 
-use alloc::vec::Vec;
-use core::time::Duration;
+use alloc::{string::String, vec::Vec};
+use core::{
+    fmt::Write,
+    future::Future,
+    mem::ManuallyDrop,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
 
+use no_std_io::io;
 use snafu::Snafu;
 use vex_sdk::{
     vexDeviceVisionBrightnessGet, vexDeviceVisionBrightnessSet, vexDeviceVisionLedColorGet,
@@ -36,8 +44,9 @@ use vex_sdk::{
     V5VisionWifiMode, V5_DeviceT, V5_DeviceVisionObject, V5_DeviceVisionRgb,
     V5_DeviceVisionSignature,
 };
+use vexide_core::{float::Float, fs};
 
-use super::{SmartDevice, SmartDeviceType, SmartPort};
+use super::{DataUpdateFuture, SmartDevice, SmartDeviceTimestamp, SmartDeviceType, SmartPort};
 use crate::{color::Rgb, geometry::Point2, PortError};
 
 /// VEX Vision Sensor
@@ -47,6 +56,7 @@ use crate::{color::Rgb, geometry::Point2, PortError};
 pub struct VisionSensor {
     port: SmartPort,
     codes: Vec<VisionCode>,
+    max_objects: Option<u8>,
     device: V5_DeviceT,
 }
 
@@ -66,6 +76,16 @@ impl VisionSensor {
     /// This value is based on the `VISION_FOV_HEIGHT` macro constant in PROS.
     pub const VERTICAL_RESOLUTION: u16 = 212;
 
+    /// The approximate horizontal field of view of the vision sensor in degrees.
+    ///
+    /// This is the value published by VEX for the V5 Vision Sensor.
+    pub const HORIZONTAL_FOV: f64 = 61.0;
+
+    /// The approximate vertical field of view of the vision sensor in degrees.
+    ///
+    /// This is the value published by VEX for the V5 Vision Sensor.
+    pub const VERTICAL_FOV: f64 = 41.0;
+
     /// The update rate of the vision sensor.
     pub const UPDATE_INTERVAL: Duration = Duration::from_millis(50);
 
@@ -82,6 +102,7 @@ impl VisionSensor {
             device: unsafe { port.device_handle() },
             port,
             codes: Vec::new(),
+            max_objects: None,
         }
     }
 
@@ -98,7 +119,7 @@ impl VisionSensor {
     /// loses power. As a result, this function should be called every time the sensor is used on
     /// program start.
     pub fn set_signature(&mut self, id: u8, signature: VisionSignature) -> Result<(), VisionError> {
-        if !(1..7).contains(&id) {
+        if !(1..=7).contains(&id) {
             return Err(VisionError::InvalidId);
         }
 
@@ -107,11 +128,11 @@ impl VisionSensor {
         let mut signature = V5_DeviceVisionSignature {
             id,
             uMin: signature.u_threshold.0,
-            uMean: signature.u_threshold.1,
-            uMax: signature.u_threshold.2,
+            uMax: signature.u_threshold.1,
+            uMean: signature.u_threshold.2,
             vMin: signature.v_threshold.0,
-            vMean: signature.v_threshold.1,
-            vMax: signature.v_threshold.2,
+            vMax: signature.v_threshold.1,
+            vMean: signature.v_threshold.2,
             range: signature.range,
             mType: if self.codes.iter().any(|code| code.contains_signature(id)) {
                 V5VisionBlockType::kVisionTypeColorCode
@@ -128,7 +149,7 @@ impl VisionSensor {
     }
 
     fn raw_signature(&self, id: u8) -> Result<Option<V5_DeviceVisionSignature>, VisionError> {
-        if !(1..7).contains(&id) {
+        if !(1..=7).contains(&id) {
             return Err(VisionError::InvalidId);
         }
 
@@ -327,16 +348,47 @@ impl VisionSensor {
         })
     }
 
+    /// Limits the number of objects returned by [`VisionSensor::objects`], or removes the limit if
+    /// `None` is passed.
+    ///
+    /// The sensor reports objects sorted by size, so a limit keeps only the largest (typically nearest)
+    /// objects. Objects past the limit are never read from the sensor, which reduces the time spent
+    /// processing each frame when only a few targets are relevant.
+    ///
+    /// The Vision Sensor has no configurable limit of its own, so this only affects how many objects
+    /// are read by the brain.
+    pub fn set_max_objects(&mut self, max: Option<u8>) -> Result<(), VisionError> {
+        self.validate_port()?;
+
+        self.max_objects = max;
+
+        Ok(())
+    }
+
+    /// Returns the maximum number of objects returned by [`VisionSensor::objects`], or `None` if there
+    /// is no limit.
+    pub const fn max_objects(&self) -> Option<u8> {
+        self.max_objects
+    }
+
     /// Returns a [`Vec`] of objects detected by the sensor.
+    ///
+    /// If a limit has been set using [`VisionSensor::set_max_objects`], at most that many objects will be
+    /// returned.
     pub fn objects(&self) -> Result<Vec<VisionObject>, VisionError> {
         if self.mode()? == VisionMode::Wifi {
             return Err(VisionError::WifiMode);
         }
 
         let object_count = unsafe { vexDeviceVisionObjectCountGet(self.device) } as usize;
-        let mut objects = Vec::with_capacity(object_count);
+        let max_objects = self.max_objects.map_or(object_count, usize::from);
+        let mut objects = Vec::with_capacity(object_count.min(max_objects));
 
         for i in 0..object_count {
+            if objects.len() >= max_objects {
+                break;
+            }
+
             let mut object = V5_DeviceVisionObject::default();
 
             if unsafe { vexDeviceVisionObjectGet(self.device, i as u32, &mut object) } == 0 {
@@ -415,9 +467,186 @@ impl VisionSensor {
 
         Ok(unsafe { vexDeviceVisionModeGet(self.device) }.into())
     }
+
+    /// Saves the sensor's signatures, color codes, brightness, and white balance to a file on the
+    /// SD card.
+    ///
+    /// The configuration can later be restored with [`VisionSensor::load_config`]. Existing files
+    /// at `path` are overwritten. See [`vexide_core::fs`] for how paths are interpreted.
+    ///
+    /// # File Format
+    ///
+    /// Configurations are stored as plain text, with one setting per line:
+    ///
+    /// ```text
+    /// vexide-vision v1
+    /// brightness 0.5
+    /// white_balance manual 255 255 255
+    /// signature 1 10049 11513 10781 -425 1 -212 4.1
+    /// code 1 2
+    /// ```
+    ///
+    /// - `white_balance` is followed by `auto`, `startup_auto`, or `manual` and an RGB color.
+    /// - `signature` lines contain the signature's ID, then its U and V thresholds as
+    ///   `min max mean`, then its range. Only signatures stored on the sensor are saved.
+    /// - `code` lines list the IDs of the signatures in each color code. Each of these signatures
+    ///   must be defined by an earlier `signature` line.
+    ///
+    /// Empty lines and lines starting with `#` are ignored when loading.
+    pub fn save_config(&self, path: &str) -> Result<(), VisionError> {
+        let mut config = String::from("vexide-vision v1\n");
+
+        // Writing to a `String` can't fail.
+        _ = writeln!(config, "brightness {}", self.brightness()?);
+
+        _ = match self.white_balance()? {
+            WhiteBalance::Auto => writeln!(config, "white_balance auto"),
+            WhiteBalance::StartupAuto => writeln!(config, "white_balance startup_auto"),
+            WhiteBalance::Manual(Rgb { r, g, b }) => {
+                writeln!(config, "white_balance manual {r} {g} {b}")
+            }
+        };
+
+        for id in 1..=7 {
+            if let Some(signature) = self.signature(id)? {
+                let (u_min, u_max, u_mean) = signature.u_threshold;
+                let (v_min, v_max, v_mean) = signature.v_threshold;
+
+                _ = writeln!(
+                    config,
+                    "signature {id} {u_min} {u_max} {u_mean} {v_min} {v_max} {v_mean} {}",
+                    signature.range
+                );
+            }
+        }
+
+        for code in &self.codes {
+            _ = write!(config, "code {} {}", code.0, code.1);
+            for id in [code.2, code.3, code.4].into_iter().flatten() {
+                _ = write!(config, " {id}");
+            }
+            config.push('\n');
+        }
+
+        fs::write(path, config).map_err(|source| VisionError::ConfigFile { source })
+    }
+
+    /// Loads a configuration saved by [`VisionSensor::save_config`] from a file on the SD card,
+    /// applying it to the sensor.
+    ///
+    /// The whole file is validated before any settings are applied, so a corrupt file leaves the
+    /// sensor unchanged. This includes checking that every color code only uses signatures defined
+    /// in the file. Color codes registered on the sensor are replaced by those in the file,
+    /// while signature slots that aren't in the file are left as they are.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`VisionError::ConfigFile`] if the file doesn't exist or couldn't be read. Its
+    ///   `source` describes what went wrong, such as no SD card being inserted.
+    /// - Returns [`VisionError::InvalidConfig`] if the file is malformed.
+    pub fn load_config(&mut self, path: &str) -> Result<(), VisionError> {
+        let contents =
+            fs::read_to_string(path).map_err(|source| VisionError::ConfigFile { source })?;
+        let config = VisionConfig::parse(&contents)?;
+
+        // The parsed configuration has been fully validated, so the only way that applying it can
+        // fail from here on is the sensor being unplugged.
+        self.validate_port()?;
+
+        // Codes determine each signature's type, so they need to be known before signatures are
+        // set. Every signature used by a code is in the file, so setting the signatures below also
+        // marks them as part of a code on the sensor.
+        self.codes = config.codes;
+
+        if let Some(brightness) = config.brightness {
+            self.set_brightness(brightness)?;
+        }
+        if let Some(white_balance) = config.white_balance {
+            self.set_white_balance(white_balance)?;
+        }
+        for (id, signature) in config.signatures.iter().enumerate() {
+            if let Some(signature) = signature {
+                self.set_signature(id as u8 + 1, *signature)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a future that measures the rate at which the sensor is sending new frames to the
+    /// brain, in frames per second.
+    ///
+    /// The sensor's frame rate varies with its [mode](VisionMode) and the complexity of the scene,
+    /// so this can be useful when tuning detection settings. The measurement waits for `frames`
+    /// new frames to arrive, using the sensor's own timestamps to time them. Measuring more frames
+    /// takes longer but gives a more stable result. If `frames` is zero, a single frame is measured.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// let fps = sensor.measure_fps(20).await?;
+    /// println!("Vision sensor is running at {fps:.1} FPS");
+    /// ```
+    pub fn measure_fps(&self, frames: u8) -> VisionFpsFuture<'_> {
+        VisionFpsFuture {
+            sensor: self,
+            update: self.next_update(),
+            frames: frames.max(1),
+            measured: 0,
+            first: None,
+        }
+    }
+
+    /// Makes a best-effort attempt to turn off WiFi streaming, ignoring any errors.
+    fn stop_streaming(&self) {
+        if self.validate_port().is_ok() {
+            unsafe {
+                vexDeviceVisionWifiModeSet(self.device, V5VisionWifiMode::kVisionWifiModeOff);
+            }
+        }
+    }
+
+    /// Resets the sensor's configuration to a known state.
+    ///
+    /// This is useful after changing many settings during a tuning session, where it may no longer
+    /// be clear what state the sensor is in.
+    ///
+    /// # Persisted Settings
+    ///
+    /// - Signatures stored in the sensor's onboard memory are kept, but are no longer part of any
+    ///   color code.
+    /// - The sensor's brightness is re-applied after the reset.
+    ///
+    /// # Cleared Settings
+    ///
+    /// - All [`VisionCode`]s registered with [`VisionSensor::add_code`] are removed.
+    /// - The detection mode is set to [`VisionMode::ColorDetection`], turning off WiFi streaming.
+    /// - White balance is set to [`WhiteBalance::Auto`].
+    /// - The LED indicator is set to [`LedMode::Auto`].
+    pub fn reset(&mut self) -> Result<(), VisionError> {
+        let brightness = self.brightness()?;
+
+        for code in core::mem::take(&mut self.codes) {
+            for id in [Some(code.0), Some(code.1), code.2, code.3, code.4]
+                .into_iter()
+                .flatten()
+            {
+                self.set_signature_type(id, V5VisionBlockType::kVisionTypeNormal.0 as _)?;
+            }
+        }
+
+        self.set_mode(VisionMode::ColorDetection)?;
+        self.set_white_balance(WhiteBalance::Auto)?;
+        self.set_led_mode(LedMode::Auto)?;
+        self.set_brightness(brightness)?;
+
+        Ok(())
+    }
 }
 
 impl SmartDevice for VisionSensor {
+    const UPDATE_INTERVAL: Duration = VisionSensor::UPDATE_INTERVAL;
+
     fn port_number(&self) -> u8 {
         self.port.number()
     }
@@ -428,7 +657,25 @@ impl SmartDevice for VisionSensor {
 }
 impl From<VisionSensor> for SmartPort {
     fn from(device: VisionSensor) -> Self {
-        device.port
+        device.stop_streaming();
+
+        // `VisionSensor` implements `Drop`, so its fields can't be moved out of it directly.
+        let mut device = ManuallyDrop::new(device);
+
+        // SAFETY: `device` is never used again after its fields are read out of it, and because it is
+        // wrapped in `ManuallyDrop`, its fields won't be dropped twice.
+        unsafe {
+            core::ptr::drop_in_place(&mut device.codes);
+            core::ptr::read(&device.port)
+        }
+    }
+}
+
+impl Drop for VisionSensor {
+    /// Turns off WiFi streaming when the sensor is dropped, since it would otherwise keep
+    /// running and using bandwidth after the sensor is no longer in use.
+    fn drop(&mut self) {
+        self.stop_streaming();
     }
 }
 
@@ -725,6 +972,149 @@ pub enum DetectionSource {
     Line,
 }
 
+/// A vision sensor configuration parsed from the format used by [`VisionSensor::save_config`].
+struct VisionConfig {
+    brightness: Option<f64>,
+    white_balance: Option<WhiteBalance>,
+    signatures: [Option<VisionSignature>; 7],
+    codes: Vec<VisionCode>,
+}
+
+impl VisionConfig {
+    fn parse(contents: &str) -> Result<Self, VisionError> {
+        let mut config = Self {
+            brightness: None,
+            white_balance: None,
+            signatures: [None; 7],
+            codes: Vec::new(),
+        };
+        let mut lines = contents.lines().enumerate();
+
+        if lines.next().map(|(_, header)| header.trim()) != Some("vexide-vision v1") {
+            return Err(VisionError::InvalidConfig { line: 1 });
+        }
+
+        for (index, line) in lines {
+            let invalid = || VisionError::InvalidConfig { line: index + 1 };
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut words = line.split_whitespace();
+            let mut next = || words.next().ok_or_else(invalid);
+
+            match next()? {
+                "brightness" => {
+                    config.brightness = Some(next()?.parse().map_err(|_| invalid())?);
+                }
+                "white_balance" => {
+                    config.white_balance = Some(match next()? {
+                        "auto" => WhiteBalance::Auto,
+                        "startup_auto" => WhiteBalance::StartupAuto,
+                        "manual" => {
+                            let mut channel = || next()?.parse::<u8>().map_err(|_| invalid());
+                            WhiteBalance::Manual(Rgb::new(channel()?, channel()?, channel()?))
+                        }
+                        _ => return Err(invalid()),
+                    });
+                }
+                "signature" => {
+                    let id: u8 = next()?.parse().map_err(|_| invalid())?;
+                    if !(1..=7).contains(&id) {
+                        return Err(invalid());
+                    }
+
+                    let mut threshold = || next()?.parse::<i32>().map_err(|_| invalid());
+                    let u_threshold = (threshold()?, threshold()?, threshold()?);
+                    let v_threshold = (threshold()?, threshold()?, threshold()?);
+                    let range = next()?.parse().map_err(|_| invalid())?;
+
+                    config.signatures[id as usize - 1] =
+                        Some(VisionSignature::new(u_threshold, v_threshold, range));
+                }
+                "code" => {
+                    let mut ids = [None; 5];
+                    for slot in &mut ids {
+                        *slot = match words.next() {
+                            Some(id) => {
+                                let id = id.parse::<u8>().map_err(|_| invalid())?;
+
+                                // Codes can only use signatures defined earlier in the file.
+                                if !(1..=7).contains(&id)
+                                    || config.signatures[id as usize - 1].is_none()
+                                {
+                                    return Err(invalid());
+                                }
+
+                                Some(id)
+                            }
+                            None => break,
+                        };
+                    }
+
+                    let [Some(sig_1), Some(sig_2), sig_3, sig_4, sig_5] = ids else {
+                        return Err(invalid());
+                    };
+                    config
+                        .codes
+                        .push(VisionCode::new(sig_1, sig_2, sig_3, sig_4, sig_5));
+                }
+                _ => return Err(invalid()),
+            }
+
+            if words.next().is_some() {
+                return Err(invalid());
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// A future that measures the frame rate of a [`VisionSensor`].
+///
+/// See [`VisionSensor::measure_fps`] for more information.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct VisionFpsFuture<'a> {
+    sensor: &'a VisionSensor,
+    update: DataUpdateFuture,
+    frames: u8,
+    measured: u8,
+    first: Option<SmartDeviceTimestamp>,
+}
+
+impl Future for VisionFpsFuture<'_> {
+    type Output = Result<f64, VisionError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            let timestamp = match Pin::new(&mut self.update).poll(cx) {
+                Poll::Ready(Ok(timestamp)) => timestamp,
+                Poll::Ready(Err(err)) => return Poll::Ready(Err(err.into())),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            // Time frames from the first update so that the measurement starts on a frame boundary.
+            match self.first {
+                None => self.first = Some(timestamp),
+                Some(first) => {
+                    self.measured += 1;
+
+                    if self.measured >= self.frames {
+                        let elapsed_ms = timestamp.0.wrapping_sub(first.0).max(1);
+                        return Poll::Ready(Ok(self.measured as f64 * 1000.0 / elapsed_ms as f64));
+                    }
+                }
+            }
+
+            self.update = self.sensor.next_update();
+        }
+    }
+}
+
 /// A detected vision object.
 ///
 /// This struct contains metadata about objects detected by the vision sensor. Objects are
@@ -753,6 +1143,49 @@ pub struct VisionObject {
     pub angle: u16,
 }
 
+impl VisionObject {
+    /// Returns the center of the object relative to the center of the camera's field of view,
+    /// scaled to a range of `-1.0` to `1.0` on each axis.
+    ///
+    /// An object in the exact center of the frame has a normalized center of `(0.0, 0.0)`.
+    /// Positive x values are to the right of the frame's center and positive y values are below
+    /// it, matching the direction of pixel coordinates. The frame's size is given by
+    /// [`VisionSensor::HORIZONTAL_RESOLUTION`] and [`VisionSensor::VERTICAL_RESOLUTION`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// if let Some(object) = sensor.objects()?.first() {
+    ///     // Turn towards the object until it's centered.
+    ///     let turn = object.normalized_center().x * 0.5;
+    /// }
+    /// ```
+    pub fn normalized_center(&self) -> Point2<f64> {
+        let half_width = VisionSensor::HORIZONTAL_RESOLUTION as f64 / 2.0;
+        let half_height = VisionSensor::VERTICAL_RESOLUTION as f64 / 2.0;
+
+        Point2 {
+            x: ((self.center.x as f64 - half_width) / half_width).clamp(-1.0, 1.0),
+            y: ((self.center.y as f64 - half_height) / half_height).clamp(-1.0, 1.0),
+        }
+    }
+
+    /// Returns the horizontal angle from the center of the camera's view to the center of the
+    /// object in degrees.
+    ///
+    /// Positive angles are to the right of the camera's center. This is estimated from the object's
+    /// [normalized center](VisionObject::normalized_center) and [`VisionSensor::HORIZONTAL_FOV`]
+    /// using an ideal pinhole camera model, so it ignores lens distortion and may be slightly
+    /// inaccurate towards the edges of the frame.
+    pub fn bearing(&self) -> f64 {
+        let half_fov = (VisionSensor::HORIZONTAL_FOV / 2.0).to_radians();
+
+        (self.normalized_center().x * half_fov.tan())
+            .atan()
+            .to_degrees()
+    }
+}
+
 impl From<V5_DeviceVisionObject> for VisionObject {
     fn from(value: V5_DeviceVisionObject) -> Self {
         Self {
@@ -861,6 +1294,24 @@ pub enum VisionError {
     /// The camera could not be read.
     ReadingFailed,
 
+    /// A configuration file could not be read from or written to the SD card.
+    #[snafu(display("Could not access the vision sensor configuration file: {source}"))]
+    ConfigFile {
+        /// The filesystem error, such as no SD card being inserted or the file not existing.
+        ///
+        /// `no_std_io` errors don't implement [`core::error::Error`], so this is reported in the
+        /// error's message rather than as its [`source`](core::error::Error::source).
+        #[snafu(source(false))]
+        source: io::Error,
+    },
+
+    /// A configuration file was malformed.
+    #[snafu(display("Invalid vision sensor configuration on line {line}."))]
+    InvalidConfig {
+        /// The line of the file containing the error.
+        line: usize,
+    },
+
     /// Generic port related error.
     #[snafu(display("{source}"), context(false))]
     Port {
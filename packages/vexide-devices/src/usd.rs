@@ -1,10 +1,12 @@
 //! USD API.
 //!
 //! The USD API provides functions for interacting with the SD card slot on the V5 Brain.
-
-use vex_sdk::vexFileDriveStatus;
+//!
+//! Files on the SD card can be read and written with [`vexide_core::fs`].
 
 /// Checks if an SD card is installed.
+///
+/// This is the same as [`vexide_core::fs::card_inserted`].
 pub fn usd_installed() -> bool {
-    unsafe { vexFileDriveStatus(0) }
+    vexide_core::fs::card_inserted()
 }
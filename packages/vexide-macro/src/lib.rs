@@ -84,6 +84,23 @@ fn make_entrypoint(inner: ItemFn, opts: MacroOpts) -> proc_macro2::TokenStream {
         quote! { false }
     };
 
+    if opts.competition {
+        return quote! {
+            #[no_mangle]
+            unsafe extern "C" fn _start() -> ! {
+                ::vexide::startup::startup::<#banner_enabled>(#banner_theme);
+
+                #inner
+                ::vexide::async_runtime::block_on(async {
+                    let robot: #ret_type = #inner_ident(
+                        ::vexide::devices::peripherals::Peripherals::take().unwrap()
+                    ).await;
+                    ::vexide::core::competition::CompeteExt::compete(robot).await
+                })
+            }
+        };
+    }
+
     quote! {
         #[no_mangle]
         unsafe extern "C" fn _start() -> ! {
@@ -114,6 +131,7 @@ fn make_entrypoint(inner: ItemFn, opts: MacroOpts) -> proc_macro2::TokenStream {
 ///
 /// - `banner`: Allows for disabling or using a custom banner theme. When `enabled = false` the banner will be disabled. `theme` can be set to a custom `BannerTheme` struct.
 /// - `code_sig`: Allows using a custom `CodeSignature` struct to configure program behavior.
+/// - `competition`: Runs the robot returned by `main` under competition control. See below.
 ///
 /// # Examples
 ///
@@ -156,6 +174,36 @@ fn make_entrypoint(inner: ItemFn, opts: MacroOpts) -> proc_macro2::TokenStream {
 /// }
 /// ```
 ///
+/// With the `competition` parameter, `main` instead sets up and returns a robot implementing
+/// `Compete`. Once `main` returns, the robot's `autonomous`, `driver`, and `disabled` functions are
+/// run as the competition mode changes, for the rest of the program.
+///
+/// ```ignore
+/// # #![no_std]
+/// # #![no_main]
+/// # use vexide::prelude::*;
+/// struct Robot {
+///     motor: Motor,
+/// }
+///
+/// impl Compete for Robot {
+///     async fn autonomous(&mut self) {
+///         println!("Autonomous!");
+///     }
+///
+///     async fn driver(&mut self) {
+///         println!("Driver!");
+///     }
+/// }
+///
+/// #[vexide::main(competition)]
+/// async fn main(peripherals: Peripherals) -> Robot {
+///     Robot {
+///         motor: Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward),
+///     }
+/// }
+/// ```
+///
 /// A custom code signature may be used to further configure the behavior of the program.
 ///
 /// ```ignore
@@ -243,6 +291,7 @@ mod test {
                 banner_enabled: false,
                 banner_theme: None,
                 code_sig: None,
+                competition: false,
             },
         );
         assert!(entrypoint.to_string().contains("false"));
@@ -254,6 +303,7 @@ mod test {
                 banner_enabled: true,
                 banner_theme: None,
                 code_sig: None,
+                competition: false,
             },
         );
         assert!(entrypoint.to_string().contains("true"));
@@ -269,6 +319,7 @@ mod test {
                 "__custom_code_sig_ident__",
                 proc_macro2::Span::call_site(),
             )),
+            competition: false,
         });
 
         println!("{}", code_sig.to_string());
@@ -277,6 +328,43 @@ mod test {
         ));
     }
 
+    #[test]
+    fn competes_with_returned_robot() {
+        let source = quote! {
+            async fn main(_peripherals: Peripherals) -> Robot {
+                Robot
+            }
+        };
+
+        let input = syn::parse2::<ItemFn>(source.clone()).unwrap();
+        let output = make_entrypoint(
+            input,
+            MacroOpts {
+                competition: true,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            output.to_string(),
+            quote! {
+                #[no_mangle]
+                unsafe extern "C" fn _start() -> ! {
+                    ::vexide::startup::startup::<true>(::vexide::startup::banner::themes::THEME_DEFAULT);
+
+                    #source
+                    ::vexide::async_runtime::block_on(async {
+                        let robot: Robot = main(
+                            ::vexide::devices::peripherals::Peripherals::take().unwrap()
+                        ).await;
+                        ::vexide::core::competition::CompeteExt::compete(robot).await
+                    })
+                }
+            }
+            .to_string()
+        );
+    }
+
     #[test]
     fn requires_async() {
         let source = quote! {
@@ -16,6 +16,8 @@ mod kw {
     custom_keyword!(theme);
 
     custom_keyword!(code_sig);
+
+    custom_keyword!(competition);
 }
 
 #[derive(Clone)]
@@ -23,6 +25,7 @@ pub struct MacroOpts {
     pub banner_enabled: bool,
     pub banner_theme: Option<Ident>,
     pub code_sig: Option<Ident>,
+    pub competition: bool,
 }
 
 impl Default for MacroOpts {
@@ -31,6 +34,7 @@ impl Default for MacroOpts {
             banner_enabled: true,
             banner_theme: None,
             code_sig: None,
+            competition: false,
         }
     }
 }
@@ -53,6 +57,7 @@ impl From<Attrs> for MacroOpts {
                     }
                 }
                 Attribute::CodeSig(code_sig) => opts.code_sig = Some(code_sig.into_ident()),
+                Attribute::Competition(_) => opts.competition = true,
             }
         }
         opts
@@ -74,6 +79,7 @@ impl Parse for Attrs {
 pub enum Attribute {
     Banner(Banner),
     CodeSig(CodeSig),
+    Competition(kw::competition),
 }
 
 impl Parse for Attribute {
@@ -83,6 +89,8 @@ impl Parse for Attribute {
             input.parse().map(Attribute::Banner)
         } else if lookahead.peek(kw::code_sig) {
             input.parse().map(Attribute::CodeSig)
+        } else if lookahead.peek(kw::competition) {
+            input.parse().map(Attribute::Competition)
         } else {
             Err(lookahead.error())
         }
@@ -312,4 +320,18 @@ mod test {
         assert!(!opts.banner_enabled);
         assert_eq!(opts.code_sig.unwrap().to_string(), "my_code_sig");
     }
+
+    #[test]
+    fn parses_competition_attribute() {
+        let input = syn::parse2::<Attrs>(quote! {}).unwrap();
+        assert!(!MacroOpts::from(input).competition);
+
+        let source = quote! {
+            competition, banner(enabled = false)
+        };
+        let input = syn::parse2::<Attrs>(source).unwrap();
+        let opts = MacroOpts::from(input);
+        assert!(opts.competition);
+        assert!(!opts.banner_enabled);
+    }
 }
@@ -0,0 +1,80 @@
+//! Fixed-capacity string buffer used for formatting without the heap.
+
+use core::{ffi::CStr, fmt};
+
+/// A string buffer with a fixed capacity of `N` bytes, stored inline.
+///
+/// Text that doesn't fit in the buffer is silently truncated at the last character that fits.
+/// One byte is always reserved for a NUL terminator so that the buffer can be passed to the SDK
+/// as a C string.
+pub(crate) struct StackBuffer<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> StackBuffer<N> {
+    /// Creates a new, empty buffer.
+    pub(crate) const fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Returns `true` if the buffer contains no text.
+    pub(crate) const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Empties the buffer.
+    pub(crate) fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Appends as much of a string as will fit into the buffer.
+    pub(crate) fn push_str(&mut self, s: &str) {
+        let available = N.saturating_sub(1) - self.len;
+
+        // Truncate on a character boundary so that the buffer remains valid UTF-8.
+        let mut end = s.len().min(available);
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        self.bytes[self.len..self.len + end].copy_from_slice(&s.as_bytes()[..end]);
+        self.len += end;
+    }
+
+    /// Appends a character to the buffer if it fits.
+    pub(crate) fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Removes trailing whitespace from the buffer.
+    pub(crate) fn trim_end(&mut self) {
+        self.len = self.as_str().trim_end().len();
+    }
+
+    /// Returns the contents of the buffer as a string slice.
+    pub(crate) fn as_str(&self) -> &str {
+        // SAFETY: The buffer is only ever written to with complete UTF-8 characters.
+        unsafe { core::str::from_utf8_unchecked(&self.bytes[..self.len]) }
+    }
+
+    /// Returns the contents of the buffer as a NUL-terminated C string.
+    ///
+    /// If the buffer contains an interior NUL byte, the string ends at that byte.
+    pub(crate) fn as_c_str(&mut self) -> &CStr {
+        self.bytes[self.len] = 0;
+
+        // The slice is guaranteed to contain a NUL byte at `self.len`.
+        CStr::from_bytes_until_nul(&self.bytes[..=self.len]).unwrap()
+    }
+}
+
+impl<const N: usize> fmt::Write for StackBuffer<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+}
@@ -3,13 +3,13 @@
 //! Supports capturing and printing backtraces to aid in debugging.
 //!
 //! If the `display_panics` feature is enabled, it will also display the panic message on the V5 Brain display.
+//!
+//! The panic handler does not allocate when formatting or drawing the panic message, so panics caused by
+//! heap exhaustion (such as the "memory allocation failed" panic raised when the allocator runs out of
+//! memory) are still reported.
 
 #![no_std]
 
-extern crate alloc;
-
-#[allow(unused_imports)]
-use alloc::string::{String, ToString};
 #[allow(unused_imports)]
 use core::fmt::Write;
 
@@ -17,10 +17,16 @@ use vexide_core::{backtrace::Backtrace, println};
 #[cfg(feature = "display_panics")]
 use vexide_devices::{
     color::Rgb,
-    display::{Display, Rect, Text, TextSize},
+    display::{Display, Rect},
     geometry::Point2,
 };
 
+#[cfg(feature = "display_panics")]
+mod buffer;
+
+#[cfg(feature = "display_panics")]
+use buffer::StackBuffer;
+
 #[cfg(target_arch = "wasm32")]
 extern "C" {
     /// Prints a backtrace to the debug console
@@ -40,18 +46,23 @@ fn draw_error(display: &mut Display, msg: &str, backtrace: &Backtrace) {
 
     display.set_render_mode(vexide_devices::display::RenderMode::Immediate);
 
-    fn draw_text(display: &mut Display, buffer: &str, line: i16) {
-        display.fill(
-            &Text::new(
-                buffer,
-                TextSize::Small,
-                Point2 {
-                    x: ERROR_BOX_MARGIN + ERROR_BOX_PADDING,
-                    y: ERROR_BOX_MARGIN + ERROR_BOX_PADDING + (line * LINE_HEIGHT),
-                },
-            ),
-            Rgb::WHITE,
-        );
+    type LineBuffer = StackBuffer<{ LINE_MAX_WIDTH * 4 + 1 }>;
+
+    // We draw text through the SDK directly rather than using `Text`, since `Text` stores its
+    // contents in a heap-allocated `CString`.
+    fn draw_text(buffer: &mut LineBuffer, line: i16) {
+        let x = ERROR_BOX_MARGIN + ERROR_BOX_PADDING;
+        let y = ERROR_BOX_MARGIN + ERROR_BOX_PADDING + (line * LINE_HEIGHT);
+
+        unsafe {
+            vex_sdk::vexDisplayForegroundColor(Rgb::WHITE.into());
+            vex_sdk::vexDisplaySmallStringAt(
+                x as _,
+                (y + Display::HEADER_HEIGHT) as _,
+                c"%s".as_ptr(),
+                buffer.as_c_str().as_ptr(),
+            );
+        }
     }
 
     let error_box_rect = Rect::new(
@@ -68,39 +79,47 @@ fn draw_error(display: &mut Display, msg: &str, backtrace: &Backtrace) {
     display.fill(&error_box_rect, Rgb::RED);
     display.stroke(&error_box_rect, Rgb::WHITE);
 
-    let mut buffer = String::new();
+    let mut buffer = LineBuffer::new();
     let mut line: i16 = 0;
+    let mut line_length = 0;
 
-    for (i, character) in msg.char_indices() {
+    for character in msg.chars() {
         if !character.is_ascii_control() {
             buffer.push(character);
+            line_length += 1;
         }
 
-        if character == '\n' || ((buffer.len() % LINE_MAX_WIDTH == 0) && (i > 0)) {
-            draw_text(display, &buffer, line);
+        if character == '\n' || line_length == LINE_MAX_WIDTH {
+            draw_text(&mut buffer, line);
             line += 1;
+            line_length = 0;
             buffer.clear();
         }
     }
 
     if !buffer.is_empty() {
-        draw_text(display, &buffer, line);
+        draw_text(&mut buffer, line);
+        buffer.clear();
 
         line += 1;
     }
 
     line += 1;
-    draw_text(display, "stack backtrace:", line);
+    buffer.push_str("stack backtrace:");
+    draw_text(&mut buffer, line);
+    buffer.clear();
     line += 1;
 
     if !backtrace.frames.is_empty() {
         const ROW_LENGTH: usize = 3;
         for (col, frames) in backtrace.frames.chunks(ROW_LENGTH).enumerate() {
-            let mut msg = String::new();
             for (row, frame) in frames.iter().enumerate() {
-                write!(msg, "{:>3}: {:?}    ", col * ROW_LENGTH + row, frame).unwrap();
+                // Writing to a `StackBuffer` never fails; it truncates instead.
+                _ = write!(buffer, "{:>3}: {:?}    ", col * ROW_LENGTH + row, frame);
             }
-            draw_text(display, msg.trim_end(), line);
+            buffer.trim_end();
+            draw_text(&mut buffer, line);
+            buffer.clear();
             line += 1;
         }
     }
@@ -114,11 +133,13 @@ pub fn panic(info: &core::panic::PanicInfo<'_>) -> ! {
     let backtrace = Backtrace::capture();
 
     #[cfg(feature = "display_panics")]
-    draw_error(
-        unsafe { &mut Display::new() },
-        &info.to_string(),
-        &backtrace,
-    );
+    {
+        // Format the message onto the stack, since the heap may be exhausted.
+        let mut msg = StackBuffer::<1024>::new();
+        _ = write!(msg, "{info}");
+
+        draw_error(unsafe { &mut Display::new() }, msg.as_str(), &backtrace);
+    }
 
     #[cfg(target_arch = "wasm32")]
     unsafe {
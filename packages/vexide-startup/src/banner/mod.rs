@@ -37,7 +37,8 @@ pub fn print(theme: BannerTheme) {
 {lp4}      *%%%%%+#%%%%%%%#=\x1B[0m        ├─\x1B{mk}🦀 Rust:\x1B[0m {rust_version}
 {lp5}        *%%%%%%%*-+%%%%%+\x1B[0m      ├─\x1B{mk}🏆 Mode:\x1B[0m {competition_mode}
 {lp6}          +%%%*:   .+###%#\x1B[0m     ├─\x1B{mk}🔋 Battery:\x1B[0m {battery}%
-{lp7}           .%:\x1B[0m                 ╰─\x1B{mk}⌚ Uptime:\x1B[0m {uptime:.2?}
+{lp7}           .%:\x1B[0m                 ├─\x1B{mk}⌚ Uptime:\x1B[0m {uptime:.2?}
+                               ╰─\x1B{mk}💾 Heap:\x1B[0m {heap:.2} MiB
 ",
         lp1 = theme.logo_primary[0],
         lp2 = theme.logo_primary[1],
@@ -65,5 +66,6 @@ pub fn print(theme: BannerTheme) {
             "Driver"
         },
         uptime = Duration::from_micros(unsafe { vexSystemPowerupTimeGet() }),
+        heap = vexide_core::allocator::total_heap() as f64 / (1024.0 * 1024.0),
     );
 }
@@ -70,6 +70,7 @@ pub mod prelude {
     #[cfg(feature = "async")]
     pub use vexide_async::{
         block_on,
+        stream::StreamExt,
         task::{spawn, Task},
         time::{sleep, sleep_until},
     };
@@ -89,6 +90,7 @@ pub mod prelude {
             analog::AdiAnalogIn,
             digital::{AdiDigitalIn, AdiDigitalOut},
             encoder::AdiEncoder,
+            gyro::AdiGyro,
             light_sensor::AdiLightSensor,
             line_tracker::AdiLineTracker,
             motor::AdiMotor,
@@ -99,17 +101,28 @@ pub mod prelude {
             AdiDevice, AdiPort,
         },
         battery,
+        calibrate::Calibrate,
         color::Rgb,
+        control::{
+            input::InputCurve,
+            odometry::{Odometry, Pose},
+            profile::TrapezoidalProfile,
+            slew::SlewRateLimiter,
+        },
         controller::Controller,
         display::Display,
+        length::Length,
         peripherals::{DynamicPeripherals, Peripherals},
-        position::Position,
+        position::{Direction, Position},
+        sensor::Sensor,
+        shared::SharedDevice,
         smart::{
             distance::DistanceSensor,
             expander::AdiExpander,
             imu::InertialSensor,
             link::{LinkType, RadioLink},
-            motor::{BrakeMode, Direction, Gearset, Motor, MotorControl},
+            motor::{BrakeMode, Gearset, Motor, MotorControl},
+            motor_group::MotorGroup,
             optical::OpticalSensor,
             rotation::RotationSensor,
             serial::SerialPort,